@@ -2,7 +2,7 @@ use mollusk_svm::{program, Mollusk};
 use mollusk_svm_bencher::MolluskComputeUnitBencher;
 use solana_pinocchio_starter::{
     instruction::{InitializeMyStateIxData, UpdateMyStateIxData},
-    state::{to_bytes, DataLen, MyState, State},
+    state::{to_bytes, AccountHeader, MyState, State},
     ID,
 };
 use solana_sdk::pubkey;
@@ -45,15 +45,16 @@ fn main() {
     // Create the instruction data
     let ix_data = InitializeMyStateIxData {
         owner: *PAYER.as_array(),
-        data: [1; 32],
+        data_len: 32,
         bump,
     };
 
     // Ix discriminator = 0
     let mut ser_ix_data = vec![0];
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(to_bytes(&ix_data));
+    // Serialize the instruction data, payload last
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    ser_ix_data.extend_from_slice(&[1; 32]);
 
     // Create instruction
     let instruction0 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
@@ -66,33 +67,42 @@ fn main() {
         (system_program, system_account.clone()),
     ];
 
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
+    let rent = mollusk.sysvars.rent.minimum_balance(MyState::size(32));
+    let mut mystate_account = Account::new(rent, MyState::size(32), &ID.into());
 
     let my_state = MyState {
+        header: AccountHeader {
+            discriminator: MyState::DISCRIMINATOR,
+            version: MyState::VERSION,
+        },
         is_initialized: true,
         owner: *PAYER.as_array(),
         state: State::Initialized,
-        data: [1; 32],
         update_count: 0,
+        pending_owner: [0; 32],
+        last_updated_slot: 0,
+        data_len: 32,
     };
 
-    mystate_account.data = to_bytes(&my_state).to_vec();
+    mystate_account.data = unsafe { to_bytes(&my_state) }.to_vec();
+    mystate_account.data.extend_from_slice(&[1; 32]);
 
     //Push the accounts in to the instruction_accounts vec!
     let ix_accounts = vec![
         AccountMeta::new(PAYER, true),
         AccountMeta::new(mystate_pda, false),
+        AccountMeta::new_readonly(system_program, false),
     ];
 
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
+    // Create the instruction data, same payload size so no rent moves
+    let ix_data = UpdateMyStateIxData { data_len: 32 };
 
     // Ix discriminator = 1
     let mut ser_ix_data = vec![1];
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(to_bytes(&ix_data));
+    // Serialize the instruction data, payload last
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    ser_ix_data.extend_from_slice(&[1; 32]);
 
     // Create instruction
     let instruction1 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
@@ -100,6 +110,7 @@ fn main() {
     let tx_accounts1 = &vec![
         (PAYER, payer_account.clone()),
         (mystate_pda, mystate_account.clone()),
+        (system_program, system_account.clone()),
     ];
 
     MolluskComputeUnitBencher::new(mollusk)
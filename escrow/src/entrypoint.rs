@@ -31,5 +31,41 @@ fn process_instruction(
             log!("Ix:1");
             instruction::process_update_state(accounts, instruction_data)
         }
+        MyProgramInstrution::Deposit => {
+            log!("Ix:2");
+            instruction::process_deposit(accounts, instruction_data)
+        }
+        MyProgramInstrution::Withdraw => {
+            log!("Ix:3");
+            instruction::process_withdraw(accounts, instruction_data)
+        }
+        MyProgramInstrution::CloseVault => {
+            log!("Ix:4");
+            instruction::process_close_vault(accounts, instruction_data)
+        }
+        MyProgramInstrution::ExtendLock => {
+            log!("Ix:5");
+            instruction::process_extend_lock(accounts, instruction_data)
+        }
+        MyProgramInstrution::TransferOwnership => {
+            log!("Ix:6");
+            instruction::process_transfer_ownership(accounts, instruction_data)
+        }
+        MyProgramInstrution::AcceptOwnership => {
+            log!("Ix:7");
+            instruction::process_accept_ownership(accounts)
+        }
+        MyProgramInstrution::CloseState => {
+            log!("Ix:8");
+            instruction::process_close_state(accounts)
+        }
+        MyProgramInstrution::FreezeState => {
+            log!("Ix:9");
+            instruction::process_freeze_state(accounts)
+        }
+        MyProgramInstrution::Migrate => {
+            log!("Ix:10");
+            instruction::process_migrate(accounts)
+        }
     }
 }
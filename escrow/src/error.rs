@@ -10,6 +10,20 @@ pub enum MyProgramError {
     PdaMismatch,
     // Invalid Owner
     InvalidOwner,
+    // Vault is still time-locked
+    VaultLocked,
+    // New unlock time is earlier than the current one
+    LockShortened,
+    // Recipient is not the vault's beneficiary
+    InvalidBeneficiary,
+    // State is frozen and takes no more updates
+    StateFrozen,
+    // Signer is not the pending owner
+    InvalidPendingOwner,
+    // Account is on an older layout, run Migrate first
+    AccountNeedsMigration,
+    // Payload is longer than MyState::MAX_DATA_LEN
+    DataTooLong,
 }
 
 impl From<MyProgramError> for ProgramError {
@@ -0,0 +1,23 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::state::MyState;
+
+pub fn process_accept_ownership(accounts: &[AccountInfo]) -> ProgramResult {
+    let [new_owner_acc, state_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !new_owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let my_state = unsafe { MyState::load_mut(state_acc.borrow_mut_data_unchecked())? };
+
+    my_state.accept_owner(new_owner_acc.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::MyProgramError, state::MyState};
+
+pub fn process_close_state(accounts: &[AccountInfo]) -> ProgramResult {
+    let [owner_acc, state_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let my_state = unsafe { MyState::load(state_acc.borrow_data_unchecked())? };
+
+    if my_state.owner.ne(owner_acc.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    // Zeroed data can't be loaded as an initialized MyState again
+    state_acc.try_borrow_mut_data()?.fill(0);
+
+    // Rent goes back to the owner, the runtime reclaims the emptied account
+    *owner_acc.try_borrow_mut_lamports()? += state_acc.lamports();
+    *state_acc.try_borrow_mut_lamports()? = 0;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_acc, load_ix_data, DataLen},
+        Vault,
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CloseVaultIxData {
+    pub bump: u8,
+}
+
+impl DataLen for CloseVaultIxData {
+    const LEN: usize = core::mem::size_of::<CloseVaultIxData>();
+}
+
+pub fn process_close_vault(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_acc, vault_acc, recipient_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !vault_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let ix_data = unsafe { load_ix_data::<CloseVaultIxData>(data)? };
+
+    Vault::validate_pda(ix_data.bump, vault_acc.key(), owner_acc.key())?;
+
+    let vault = unsafe { load_acc::<Vault>(vault_acc.borrow_data_unchecked())? };
+
+    if vault.recipient().ne(recipient_acc.key()) {
+        return Err(MyProgramError::InvalidBeneficiary.into());
+    }
+
+    vault.check_unlocked(Clock::get()?.unix_timestamp)?;
+
+    // The savings go to the recipient and the rent back to the owner who paid it,
+    // the runtime reclaims the emptied vault at the end of the transaction
+    let rent_exempt = Rent::get()?.minimum_balance(vault_acc.data_len());
+    let savings = vault_acc.lamports().saturating_sub(rent_exempt);
+
+    *recipient_acc.try_borrow_mut_lamports()? += savings;
+    *owner_acc.try_borrow_mut_lamports()? += vault_acc.lamports() - savings;
+    *vault_acc.try_borrow_mut_lamports()? = 0;
+
+    vault_acc.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
+}
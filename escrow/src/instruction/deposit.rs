@@ -1,15 +1,30 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::self, ProgramResult};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
 
-use pinocchio_system::instructions::Transfer;
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
-use solana_sdk::native_token::LAMPORTS_PER_SOL;
-
-use crate::state::{DataLen, load_ix_data};
+use crate::state::{
+    utils::{load_acc_mut, load_ix_data, DataLen},
+    Vault,
+};
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct DepositIxData {
-    pub amount: u16,
+    /// Lamports moved into the vault.
+    pub amount: u64,
+    /// Unix timestamp the vault stays locked until, only read when the
+    /// vault is created. Later deposits can push it out but never pull it in.
+    pub unlock_at: i64,
+    /// Who withdrawals pay out to, all zeroes for the owner. Only read when the
+    /// vault is created.
+    pub beneficiary: Pubkey,
     pub bump: u8,
 }
 
@@ -17,37 +32,88 @@ impl DataLen for DepositIxData {
     const LEN: usize = core::mem::size_of::<DepositIxData>();
 }
 
-pub fn process_deposit(
-    accounts: &[AccountInfo],
-    data: &[u8],
-) -> Result<()> {
-    // check for accounts
-
-    let [deposit_account: &AccountInfo, vault_account: &AccountInfo, system_program: &AccountInfo] = &accounts[..] else {
-        return Err(ProgramError::InvalidAccountData);
+pub fn process_deposit(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_acc, vault_acc, _system_program, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // let amount = u64::from_le_bytes(data);
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
-    if !deposit_account.is_signer() {
-        return Err(ProgramError::MissingRequiredSigner);
+    let ix_data = unsafe { load_ix_data::<DepositIxData>(data)? };
+
+    if ix_data.amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
     }
 
-    let ix_data = load_ix_data::<DepositIxData>(data)?;
+    Vault::validate_pda(ix_data.bump, vault_acc.key(), owner_acc.key())?;
+
+    // First deposit takes the vault over for the program and sets its lock
+    if !vault_acc.is_owned_by(&crate::ID) {
+        let pda_bump_bytes = [ix_data.bump];
 
-    let seeds = &[b"vault", deposit_account.key.as_ref(), &[ix_data.bump]];
+        // Signer seeds
+        let signer_seeds = [
+            Seed::from(Vault::SEED.as_bytes()),
+            Seed::from(owner_acc.key()),
+            Seed::from(&pda_bump_bytes[..]),
+        ];
+        let signers = [Signer::from(&signer_seeds[..])];
 
-    let vault_pda = pubkey::create_program_address(&seeds, &crate::ID).unwrap();
+        let rent_exempt = Rent::get()?.minimum_balance(Vault::LEN);
 
-    if vault_account.key != vault_pda {
-        return Err(ProgramError::InvalidAccountData);
+        if vault_acc.lamports() == 0 {
+            CreateAccount {
+                from: owner_acc,
+                to: vault_acc,
+                space: Vault::LEN as u64,
+                owner: &crate::ID,
+                lamports: rent_exempt,
+            }
+            .invoke_signed(&signers)?;
+        } else {
+            // Someone sent lamports to the address ahead of time, CreateAccount
+            // would fail, so top it up, allocate and assign it instead
+            if vault_acc.lamports() < rent_exempt {
+                Transfer {
+                    from: owner_acc,
+                    to: vault_acc,
+                    lamports: rent_exempt - vault_acc.lamports(),
+                }
+                .invoke()?;
+            }
+
+            Allocate {
+                account: vault_acc,
+                space: Vault::LEN as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: vault_acc,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        Vault::initialize(vault_acc, owner_acc.key(), ix_data)?;
+    } else {
+        let vault = unsafe { load_acc_mut::<Vault>(vault_acc.borrow_mut_data_unchecked())? };
+
+        if ix_data.unlock_at > vault.unlock_at {
+            vault.extend_lock(ix_data.unlock_at)?;
+        }
     }
 
+    // Crediting a program-owned account through the System Program is fine,
+    // only debits need the owner
     Transfer {
-        from: deposit_account,
-        to: vault_account,
-        lamports: ix_data.amount * LAMPORTS_PER_SOL,
-    }.invoke()?;
+        from: owner_acc,
+        to: vault_acc,
+        lamports: ix_data.amount,
+    }
+    .invoke()?;
 
     Ok(())
 }
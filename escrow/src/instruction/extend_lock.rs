@@ -0,0 +1,46 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Vault,
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendLockIxData {
+    /// New unlock timestamp, no earlier than the current one.
+    pub unlock_at: i64,
+}
+
+impl DataLen for ExtendLockIxData {
+    const LEN: usize = core::mem::size_of::<ExtendLockIxData>();
+}
+
+pub fn process_extend_lock(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_acc, vault_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !vault_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let vault = unsafe { load_acc_mut::<Vault>(vault_acc.borrow_mut_data_unchecked())? };
+
+    if vault.owner.ne(owner_acc.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    let ix_data = unsafe { load_ix_data::<ExtendLockIxData>(data)? };
+
+    vault.extend_lock(ix_data.unlock_at)?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::MyProgramError, state::MyState};
+
+pub fn process_freeze_state(accounts: &[AccountInfo]) -> ProgramResult {
+    let [owner_acc, state_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let my_state = unsafe { MyState::load_mut(state_acc.borrow_mut_data_unchecked())? };
+
+    if my_state.owner.ne(owner_acc.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    my_state.freeze()?;
+
+    Ok(())
+}
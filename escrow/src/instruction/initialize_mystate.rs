@@ -12,7 +12,7 @@ use pinocchio_system::instructions::CreateAccount;
 use crate::{
     error::MyProgramError,
     state::{
-        utils::{load_ix_data, DataLen},
+        utils::{load_ix_data_with_payload, DataLen},
         MyState,
     },
 };
@@ -21,12 +21,13 @@ use crate::{
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct InitializeMyStateIxData {
     pub owner: Pubkey,
-    pub data: [u8; 32],
+    /// Length of the payload following this struct in the instruction data.
+    pub data_len: u16,
     pub bump: u8,
 }
 
 impl DataLen for InitializeMyStateIxData {
-    const LEN: usize = core::mem::size_of::<InitializeMyStateIxData>(); // 32 bytes for Pubkey + 32 bytes for data
+    const LEN: usize = core::mem::size_of::<InitializeMyStateIxData>(); // 32 bytes for Pubkey + 2 bytes for data_len + 1 byte for bump + 1 byte padding
 }
 
 pub fn process_initilaize_state(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
@@ -44,7 +45,9 @@ pub fn process_initilaize_state(accounts: &[AccountInfo], data: &[u8]) -> Progra
 
     let rent = Rent::from_account_info(sysvar_rent_acc)?;
 
-    let ix_data = unsafe { load_ix_data::<InitializeMyStateIxData>(data)? };
+    let (ix_data, payload) = unsafe { load_ix_data_with_payload::<InitializeMyStateIxData>(data)? };
+
+    MyState::check_payload(ix_data.data_len, payload)?;
 
     if ix_data.owner.ne(payer_acc.key()) {
         return Err(MyProgramError::InvalidOwner.into());
@@ -61,17 +64,19 @@ pub fn process_initilaize_state(accounts: &[AccountInfo], data: &[u8]) -> Progra
         Seed::from(&pda_bump_bytes[..]),
     ];
     let signers = [Signer::from(&signer_seeds[..])];
+    let space = MyState::size(payload.len());
+
     // Create the governance config account
     CreateAccount {
         from: payer_acc,
         to: state_acc,
-        space: MyState::LEN as u64,
+        space: space as u64,
         owner: &crate::ID,
-        lamports: rent.minimum_balance(MyState::LEN),
+        lamports: rent.minimum_balance(space),
     }
     .invoke_signed(&signers)?;
 
-    MyState::initialize(state_acc, ix_data)?;
+    MyState::initialize(state_acc, ix_data, payload)?;
 
     Ok(())
 }
@@ -0,0 +1,65 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::Transfer;
+
+use crate::state::{
+    utils::{load_acc, load_acc_mut_unchecked, DataLen},
+    Layout, MyState, MyStateV1, MyStateV2,
+};
+
+/// Brings a `MyState` account up to `MyState::VERSION`. Anyone can pay for it,
+/// the fields carry over unchanged so the owner loses nothing.
+pub fn process_migrate(accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer_acc, state_acc, _system_program, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !payer_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Older layouts kept a fixed 32-byte payload inside the struct
+    let (migrated, payload) = match MyState::layout(unsafe { state_acc.borrow_data_unchecked() })? {
+        Layout::V1 => {
+            let v1 = unsafe { load_acc::<MyStateV1>(state_acc.borrow_data_unchecked())? };
+            (MyState::from_v1(v1), v1.data)
+        }
+        Layout::V2 => {
+            let v2 = unsafe { load_acc::<MyStateV2>(state_acc.borrow_data_unchecked())? };
+            (MyState::from_v2(v2), v2.data)
+        }
+        // Already up to date
+        Layout::Current => return Ok(()),
+    };
+
+    // The payer covers whatever the larger layout needs on top of the current rent
+    let space = MyState::size(payload.len());
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+
+    if state_acc.lamports() < rent_exempt {
+        Transfer {
+            from: payer_acc,
+            to: state_acc,
+            lamports: rent_exempt - state_acc.lamports(),
+        }
+        .invoke()?;
+    }
+
+    state_acc.realloc(space, false)?;
+
+    let (my_state, state_data) =
+        unsafe { state_acc.borrow_mut_data_unchecked() }.split_at_mut(MyState::LEN);
+    *unsafe { load_acc_mut_unchecked::<MyState>(my_state)? } = migrated;
+    state_data.copy_from_slice(&payload);
+
+    Ok(())
+}
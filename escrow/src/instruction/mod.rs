@@ -1,19 +1,42 @@
 use pinocchio::program_error::ProgramError;
 
+pub mod accept_ownership;
+pub mod close_state;
+pub mod close_vault;
+pub mod deposit;
+pub mod extend_lock;
+pub mod freeze_state;
 pub mod initialize_mystate;
+pub mod migrate;
+pub mod transfer_ownership;
 pub mod update_mystate;
-pub mod deposit;
 pub mod withdraw;
 
+pub use accept_ownership::*;
+pub use close_state::*;
+pub use close_vault::*;
+pub use deposit::*;
+pub use extend_lock::*;
+pub use freeze_state::*;
 pub use initialize_mystate::*;
+pub use migrate::*;
+pub use transfer_ownership::*;
 pub use update_mystate::*;
-pub use deposit::*;
 pub use withdraw::*;
 
 #[repr(u8)]
 pub enum MyProgramInstrution {
     InitializeState,
     UpdateState,
+    Deposit,
+    Withdraw,
+    CloseVault,
+    ExtendLock,
+    TransferOwnership,
+    AcceptOwnership,
+    CloseState,
+    FreezeState,
+    Migrate,
 }
 
 impl TryFrom<&u8> for MyProgramInstrution {
@@ -23,6 +46,15 @@ impl TryFrom<&u8> for MyProgramInstrution {
         match *value {
             0 => Ok(MyProgramInstrution::InitializeState),
             1 => Ok(MyProgramInstrution::UpdateState),
+            2 => Ok(MyProgramInstrution::Deposit),
+            3 => Ok(MyProgramInstrution::Withdraw),
+            4 => Ok(MyProgramInstrution::CloseVault),
+            5 => Ok(MyProgramInstrution::ExtendLock),
+            6 => Ok(MyProgramInstrution::TransferOwnership),
+            7 => Ok(MyProgramInstrution::AcceptOwnership),
+            8 => Ok(MyProgramInstrution::CloseState),
+            9 => Ok(MyProgramInstrution::FreezeState),
+            10 => Ok(MyProgramInstrution::Migrate),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -0,0 +1,48 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_ix_data, DataLen},
+        MyState,
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransferOwnershipIxData {
+    /// Proposed owner, who still has to accept. All zeroes cancels a pending transfer.
+    pub new_owner: Pubkey,
+}
+
+impl DataLen for TransferOwnershipIxData {
+    const LEN: usize = core::mem::size_of::<TransferOwnershipIxData>(); // 32 bytes for Pubkey
+}
+
+pub fn process_transfer_ownership(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_acc, state_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let my_state = unsafe { MyState::load_mut(state_acc.borrow_mut_data_unchecked())? };
+
+    if my_state.owner.ne(owner_acc.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    let ix_data = unsafe { load_ix_data::<TransferOwnershipIxData>(data)? };
+
+    my_state.propose_owner(&ix_data.new_owner)?;
+
+    Ok(())
+}
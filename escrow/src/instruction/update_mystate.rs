@@ -1,9 +1,16 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::Transfer;
 
 use crate::{
     error::MyProgramError,
     state::{
-        utils::{load_acc_mut, load_ix_data, DataLen},
+        utils::{load_acc_mut, load_ix_data_with_payload, DataLen},
         MyState,
     },
 };
@@ -11,15 +18,16 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UpdateMyStateIxData {
-    pub data: [u8; 32],
+    /// Length of the payload following this struct in the instruction data.
+    pub data_len: u16,
 }
 
 impl DataLen for UpdateMyStateIxData {
-    const LEN: usize = core::mem::size_of::<UpdateMyStateIxData>(); // 32 bytes for data
+    const LEN: usize = core::mem::size_of::<UpdateMyStateIxData>(); // 2 bytes for data_len
 }
 
 pub fn process_update_state(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [payer_acc, state_acc] = accounts else {
+    let [payer_acc, state_acc, _system_program, _rest @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -27,15 +35,50 @@ pub fn process_update_state(accounts: &[AccountInfo], data: &[u8]) -> ProgramRes
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let my_state = unsafe { load_acc_mut::<MyState>(state_acc.borrow_mut_data_unchecked())? };
+    if !state_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let my_state = unsafe { MyState::load(state_acc.borrow_data_unchecked())? };
 
     if my_state.owner.ne(payer_acc.key()) {
         return Err(MyProgramError::InvalidOwner.into());
     }
 
-    let ix_data = unsafe { load_ix_data::<UpdateMyStateIxData>(data)? };
+    my_state.check_updatable()?;
+
+    let (ix_data, payload) = unsafe { load_ix_data_with_payload::<UpdateMyStateIxData>(data)? };
+
+    MyState::check_payload(ix_data.data_len, payload)?;
+
+    // Keep the account exactly rent-exempt for its new size, the owner pays
+    // for growth and gets the surplus back on shrink
+    let space = MyState::size(payload.len());
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+
+    if state_acc.lamports() < rent_exempt {
+        Transfer {
+            from: payer_acc,
+            to: state_acc,
+            lamports: rent_exempt - state_acc.lamports(),
+        }
+        .invoke()?;
+    } else if state_acc.lamports() > rent_exempt {
+        let surplus = state_acc.lamports() - rent_exempt;
+        *state_acc.try_borrow_mut_lamports()? -= surplus;
+        *payer_acc.try_borrow_mut_lamports()? += surplus;
+    }
+
+    state_acc.realloc(space, false)?;
+
+    // data_len is stale until update() below, so load the fixed part directly
+    let (my_state, state_data) =
+        unsafe { state_acc.borrow_mut_data_unchecked() }.split_at_mut(MyState::LEN);
+    let my_state = unsafe { load_acc_mut::<MyState>(my_state)? };
+
+    state_data.copy_from_slice(payload);
 
-    my_state.update(ix_data)?;
+    my_state.update(ix_data, Clock::get()?.slot)?;
 
     Ok(())
 }
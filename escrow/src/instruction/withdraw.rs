@@ -1,59 +1,71 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::self};
-
-use pinocchio_system::instruction::Transfer;
-
-use solana_sdk::native_token::LAMPORTS_PER_SOL;
-
-use crate::state::{DataLen, load_ix_data};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_acc, load_ix_data, DataLen},
+        Vault,
+    },
+};
 
 #[repr(C)]
-#[derive(Clone, Debug, Copy, Default)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WithdrawIxData {
-    pub bump: u8;
+    /// Lamports paid out to the vault's recipient.
+    pub amount: u64,
+    pub bump: u8,
 }
 
-impl DataLen for WithdrawIxData{
+impl DataLen for WithdrawIxData {
     const LEN: usize = core::mem::size_of::<WithdrawIxData>();
 }
 
-pub fn process_withdraw(
-    accounts: &[AccountInfo]
-) -> Result<()> {
-    // check for accounts
+pub fn process_withdraw(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_acc, vault_acc, recipient_acc, _rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
 
-    let [withdraw_acc: &AccountInfo, vault_acc: &AccountInfo, _system_program: &AccountInfo] = &accounts[..] else {
-        return Err(ProgramError::InvalidAccountData);
+    if !owner_acc.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
     }
 
-    if !withdraw_acc.is_signer() {
-        return Err(ProgramError::MissingRequiredSigner);
+    if !vault_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
     }
 
-    let bump = data[0];
+    let ix_data = unsafe { load_ix_data::<WithdrawIxData>(data)? };
 
-    let seeds = &[b"vault", withdraw_acc.key.as_ref(), &[bump]];
+    if ix_data.amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // The vault is derived from the signer, so only its owner can drain it
+    Vault::validate_pda(ix_data.bump, vault_acc.key(), owner_acc.key())?;
 
-    let vault_pda = pubkey::find_program_address(&seeds, &crate::ID).unwrap();
+    let vault = unsafe { load_acc::<Vault>(vault_acc.borrow_data_unchecked())? };
 
-    if vault_acc.key != vault_pda {
-        return Err(ProgramError::InvalidAccountData);
+    if vault.recipient().ne(recipient_acc.key()) {
+        return Err(MyProgramError::InvalidBeneficiary.into());
     }
 
-    let binding_bump = [bump];
+    vault.check_unlocked(Clock::get()?.unix_timestamp)?;
 
-    let signer_seeds = [
-        Seed::from("vault".as_bytes()),
-        Seed::from(withdraw_acc.key.as_ref()),
-        Seed::from(&binding_bump),
-    ];
+    // The rent-exempt minimum stays behind, CloseVault is the only way out for it
+    let rent_exempt = Rent::get()?.minimum_balance(vault_acc.data_len());
+    let available = vault_acc.lamports().saturating_sub(rent_exempt);
 
-    let signer = [Signer::from(&signer_seeds)];
+    if ix_data.amount > available {
+        return Err(ProgramError::InsufficientFunds);
+    }
 
-    Transfer{
-        from: vault_acc,
-        to: withdraw_acc,
-        lamports: vault_acc.lamports()
-    }.invoke_signed(&signer)?;
+    // The program owns the vault, so lamports move without a CPI
+    *vault_acc.try_borrow_mut_lamports()? -= ix_data.amount;
+    *recipient_acc.try_borrow_mut_lamports()? += ix_data.amount;
 
     Ok(())
 }
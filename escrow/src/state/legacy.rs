@@ -0,0 +1,51 @@
+use super::{
+    utils::{AccountHeader, DataLen, Initialized},
+    State,
+};
+use pinocchio::pubkey::Pubkey;
+
+/// `MyState` as first deployed, before accounts carried an `AccountHeader`.
+/// Such accounts are told apart by their size and brought up to date by `Migrate`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MyStateV1 {
+    pub is_initialized: bool,
+    pub owner: Pubkey,
+    pub state: State,
+    pub data: [u8; 32],
+    pub update_count: u32,
+}
+
+impl DataLen for MyStateV1 {
+    const LEN: usize = core::mem::size_of::<MyStateV1>();
+}
+
+impl Initialized for MyStateV1 {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// `MyState` version 2, with the payload still a fixed 32 bytes inside the struct.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MyStateV2 {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub state: State,
+    pub update_count: u32,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub data: [u8; 32],
+    pub last_updated_slot: u64,
+}
+
+impl DataLen for MyStateV2 {
+    const LEN: usize = core::mem::size_of::<MyStateV2>();
+}
+
+impl Initialized for MyStateV2 {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
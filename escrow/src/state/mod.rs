@@ -1,5 +1,9 @@
+pub mod legacy;
 pub mod my_state;
 pub mod utils;
+pub mod vault;
 
+pub use legacy::*;
 pub use my_state::*;
 pub use utils::*;
+pub use vault::*;
@@ -1,4 +1,10 @@
-use super::utils::{load_acc_mut_unchecked, DataLen, Initialized};
+use super::{
+    utils::{
+        load_acc, load_acc_mut, load_acc_mut_unchecked, load_header, AccountHeader, DataLen,
+        Initialized,
+    },
+    MyStateV1, MyStateV2,
+};
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
@@ -17,16 +23,36 @@ pub enum State {
     Uninitialized,
     Initialized,
     Updated,
+    /// No more updates, the state can still change hands or be closed.
+    Frozen,
+}
+
+/// Layouts a `MyState` account can be in, oldest first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Layout {
+    /// `MyStateV1`, no header.
+    V1,
+    /// `MyStateV2`, header version 2.
+    V2,
+    /// `MyState`, header version `MyState::VERSION`.
+    Current,
 }
 
+/// Fixed part of a `MyState` account, followed by `data_len` bytes of payload.
 #[repr(C)] //keeps the struct layout the same across different architectures
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MyState {
+    pub header: AccountHeader,
     pub is_initialized: bool,
-    pub owner: Pubkey,
     pub state: State,
-    pub data: [u8; 32],
     pub update_count: u32,
+    pub owner: Pubkey,
+    /// Owner proposed by `TransferOwnership`, all zeroes when none is pending.
+    pub pending_owner: Pubkey,
+    /// Slot of the last `UpdateState`, 0 when never updated since migrating.
+    pub last_updated_slot: u64,
+    /// Length of the payload stored right after this struct.
+    pub data_len: u16,
 }
 
 impl DataLen for MyState {
@@ -42,6 +68,126 @@ impl Initialized for MyState {
 impl MyState {
     pub const SEED: &'static str = "mystate";
 
+    pub const DISCRIMINATOR: u8 = 1;
+
+    /// Bump whenever the layout changes and teach `Migrate` the previous one.
+    pub const VERSION: u8 = 3;
+
+    /// Largest payload a state account holds.
+    pub const MAX_DATA_LEN: usize = 4096;
+
+    /// Account size for a payload of `data_len` bytes.
+    pub const fn size(data_len: usize) -> usize {
+        Self::LEN + data_len
+    }
+
+    /// Layout of a `MyState` account, the headerless ones are told apart by size.
+    pub fn layout(bytes: &[u8]) -> Result<Layout, ProgramError> {
+        // A headerless account starts with `is_initialized` and the owner, which
+        // can look like a header, so the payload length has to add up as well
+        if bytes.len() >= Self::LEN {
+            let my_state = unsafe { &*(bytes.as_ptr() as *const MyState) };
+
+            if my_state.header.discriminator == Self::DISCRIMINATOR
+                && my_state.header.version == Self::VERSION
+                && bytes.len() == Self::size(my_state.data_len as usize)
+            {
+                return Ok(Layout::Current);
+            }
+        }
+
+        if bytes.len() == MyStateV1::LEN {
+            return Ok(Layout::V1);
+        }
+
+        let header = unsafe { load_header(bytes)? };
+
+        if header.discriminator == Self::DISCRIMINATOR
+            && header.version == 2
+            && bytes.len() == MyStateV2::LEN
+        {
+            return Ok(Layout::V2);
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// Loads an up-to-date account, older layouts have to be migrated first.
+    ///
+    /// # Safety
+    ///
+    /// No other reference into `bytes` may be alive while the result is used.
+    #[inline(always)]
+    pub unsafe fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        match Self::layout(bytes)? {
+            Layout::Current => load_acc_mut::<Self>(&mut bytes[..Self::LEN]),
+            _ => Err(MyProgramError::AccountNeedsMigration.into()),
+        }
+    }
+
+    /// Read-only counterpart of `load_mut`.
+    ///
+    /// # Safety
+    ///
+    /// No mutable reference into `bytes` may be alive while the result is used.
+    #[inline(always)]
+    pub unsafe fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        match Self::layout(bytes)? {
+            Layout::Current => load_acc::<Self>(&bytes[..Self::LEN]),
+            _ => Err(MyProgramError::AccountNeedsMigration.into()),
+        }
+    }
+
+    /// Payload of an account already checked by `load`.
+    pub fn payload(bytes: &[u8]) -> &[u8] {
+        &bytes[Self::LEN..]
+    }
+
+    /// Checks a payload against its length prefix and `MAX_DATA_LEN`.
+    pub fn check_payload(data_len: u16, payload: &[u8]) -> ProgramResult {
+        if payload.len() != data_len as usize {
+            return Err(MyProgramError::InvalidInstructionData.into());
+        }
+        if payload.len() > Self::MAX_DATA_LEN {
+            return Err(MyProgramError::DataTooLong.into());
+        }
+        Ok(())
+    }
+
+    /// Current layout of a version 1 account, its 32 data bytes become the payload.
+    pub fn from_v1(v1: &MyStateV1) -> Self {
+        Self {
+            header: AccountHeader {
+                discriminator: Self::DISCRIMINATOR,
+                version: Self::VERSION,
+            },
+            is_initialized: v1.is_initialized,
+            state: v1.state,
+            update_count: v1.update_count,
+            owner: v1.owner,
+            pending_owner: Pubkey::default(),
+            last_updated_slot: 0,
+            data_len: v1.data.len() as u16,
+        }
+    }
+
+    /// Current layout of a version 2 account, its 32 data bytes become the payload.
+    pub fn from_v2(v2: &MyStateV2) -> Self {
+        Self {
+            header: AccountHeader {
+                discriminator: Self::DISCRIMINATOR,
+                version: Self::VERSION,
+            },
+            is_initialized: v2.is_initialized,
+            state: v2.state,
+            update_count: v2.update_count,
+            owner: v2.owner,
+            pending_owner: v2.pending_owner,
+            last_updated_slot: v2.last_updated_slot,
+            data_len: v2.data.len() as u16,
+        }
+    }
+
     pub fn validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
         let seed_with_bump = &[Self::SEED.as_bytes(), owner, &[bump]];
         let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
@@ -54,25 +200,70 @@ impl MyState {
     pub fn initialize(
         my_stata_acc: &AccountInfo,
         ix_data: &InitializeMyStateIxData,
+        payload: &[u8],
     ) -> ProgramResult {
-        let my_state =
-            unsafe { load_acc_mut_unchecked::<MyState>(my_stata_acc.borrow_mut_data_unchecked()) }?;
+        let (my_state, data) =
+            unsafe { my_stata_acc.borrow_mut_data_unchecked() }.split_at_mut(Self::LEN);
+        let my_state = unsafe { load_acc_mut_unchecked::<MyState>(my_state) }?;
 
+        my_state.header = AccountHeader {
+            discriminator: Self::DISCRIMINATOR,
+            version: Self::VERSION,
+        };
         my_state.owner = ix_data.owner;
         my_state.state = State::Initialized;
-        my_state.data = ix_data.data;
+        my_state.data_len = ix_data.data_len;
         my_state.update_count = 0;
+        my_state.pending_owner = Pubkey::default();
+        my_state.last_updated_slot = 0;
         my_state.is_initialized = true;
 
+        data.copy_from_slice(payload);
+
+        Ok(())
+    }
+
+    pub fn check_updatable(&self) -> ProgramResult {
+        if self.state == State::Frozen {
+            return Err(MyProgramError::StateFrozen.into());
+        }
         Ok(())
     }
 
-    pub fn update(&mut self, ix_data: &UpdateMyStateIxData) -> ProgramResult {
-        self.data = ix_data.data;
+    /// Records an update, the caller has already resized the account and
+    /// written the new payload.
+    pub fn update(&mut self, ix_data: &UpdateMyStateIxData, slot: u64) -> ProgramResult {
+        self.check_updatable()?;
+
+        self.data_len = ix_data.data_len;
         if self.state != State::Updated {
             self.state = State::Updated;
         }
         self.update_count += 1;
+        self.last_updated_slot = slot;
+
+        Ok(())
+    }
+
+    pub fn propose_owner(&mut self, new_owner: &Pubkey) -> ProgramResult {
+        self.pending_owner = *new_owner;
+
+        Ok(())
+    }
+
+    pub fn accept_owner(&mut self, new_owner: &Pubkey) -> ProgramResult {
+        if self.pending_owner == Pubkey::default() || self.pending_owner.ne(new_owner) {
+            return Err(MyProgramError::InvalidPendingOwner.into());
+        }
+
+        self.owner = self.pending_owner;
+        self.pending_owner = Pubkey::default();
+
+        Ok(())
+    }
+
+    pub fn freeze(&mut self) -> ProgramResult {
+        self.state = State::Frozen;
 
         Ok(())
     }
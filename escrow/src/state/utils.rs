@@ -65,3 +65,48 @@ pub unsafe fn to_bytes<T: DataLen>(data: &T) -> &[u8] {
 pub unsafe fn to_mut_bytes<T: DataLen>(data: &mut T) -> &mut [u8] {
     core::slice::from_raw_parts_mut(data as *mut T as *mut u8, T::LEN)
 }
+
+/// Leading bytes of every versioned account, telling its type and layout apart.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccountHeader {
+    pub discriminator: u8,
+    pub version: u8,
+}
+
+impl DataLen for AccountHeader {
+    const LEN: usize = core::mem::size_of::<AccountHeader>();
+}
+
+/// Reads the header at the start of an account's data.
+///
+/// # Safety
+///
+/// `bytes` has to hold at least `AccountHeader::LEN` bytes, which is checked,
+/// and no mutable reference into them may be alive while the result is used.
+/// The header is two `u8`s, so any alignment and byte pattern is valid.
+#[inline(always)]
+pub unsafe fn load_header(bytes: &[u8]) -> Result<&AccountHeader, ProgramError> {
+    if bytes.len() < AccountHeader::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(&*(bytes.as_ptr() as *const AccountHeader))
+}
+
+/// Splits instruction data into its fixed part and the payload trailing it.
+///
+/// # Safety
+///
+/// `T` has to be a `repr(C)` type with an alignment of 1 for which any byte
+/// pattern is valid, instruction data comes with no alignment guarantee.
+/// `bytes` has to hold at least `T::LEN` bytes, which is checked.
+#[inline(always)]
+pub unsafe fn load_ix_data_with_payload<T: DataLen>(
+    bytes: &[u8],
+) -> Result<(&T, &[u8]), ProgramError> {
+    if bytes.len() < T::LEN {
+        return Err(MyProgramError::InvalidInstructionData.into());
+    }
+    let (data, payload) = bytes.split_at(T::LEN);
+    Ok((&*(data.as_ptr() as *const T), payload))
+}
@@ -0,0 +1,86 @@
+use super::utils::{load_acc_mut_unchecked, DataLen, Initialized};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    ProgramResult,
+};
+
+use crate::{error::MyProgramError, instruction::DepositIxData};
+
+/// Lamport vault of one owner, a program-owned account at the `["vault", owner]`
+/// PDA. It is created on the first deposit, which also sets its time lock, and
+/// keeps its rent-exempt minimum until `CloseVault`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vault {
+    pub owner: Pubkey,
+    /// Receives withdrawals in place of the owner, all zeroes when there is none.
+    pub beneficiary: Pubkey,
+    /// Unix timestamp before which nothing leaves the vault.
+    pub unlock_at: i64,
+    pub is_initialized: bool,
+}
+
+impl DataLen for Vault {
+    const LEN: usize = core::mem::size_of::<Vault>();
+}
+
+impl Initialized for Vault {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Vault {
+    pub const SEED: &'static str = "vault";
+
+    pub fn validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), owner, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *pda {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn initialize(
+        vault_acc: &AccountInfo,
+        owner: &Pubkey,
+        ix_data: &DepositIxData,
+    ) -> ProgramResult {
+        let vault =
+            unsafe { load_acc_mut_unchecked::<Vault>(vault_acc.borrow_mut_data_unchecked()) }?;
+
+        vault.owner = *owner;
+        vault.beneficiary = ix_data.beneficiary;
+        vault.unlock_at = ix_data.unlock_at;
+        vault.is_initialized = true;
+
+        Ok(())
+    }
+
+    /// Account the vault pays out to.
+    pub fn recipient(&self) -> &Pubkey {
+        if self.beneficiary == Pubkey::default() {
+            &self.owner
+        } else {
+            &self.beneficiary
+        }
+    }
+
+    pub fn check_unlocked(&self, now: i64) -> ProgramResult {
+        if now < self.unlock_at {
+            return Err(MyProgramError::VaultLocked.into());
+        }
+        Ok(())
+    }
+
+    pub fn extend_lock(&mut self, unlock_at: i64) -> ProgramResult {
+        if unlock_at < self.unlock_at {
+            return Err(MyProgramError::LockShortened.into());
+        }
+        self.unlock_at = unlock_at;
+        Ok(())
+    }
+}
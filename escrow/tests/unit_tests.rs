@@ -3,13 +3,20 @@ use mollusk_svm::{program, Mollusk};
 use solana_sdk::account::Account;
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::program_error::ProgramError;
 use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
 extern crate alloc;
 use alloc::vec;
 
-use solana_pinocchio_starter::instruction::{InitializeMyStateIxData, UpdateMyStateIxData};
-use solana_pinocchio_starter::state::{to_bytes, DataLen, MyState, State};
+use solana_pinocchio_starter::error::MyProgramError;
+use solana_pinocchio_starter::instruction::{
+    CloseVaultIxData, DepositIxData, ExtendLockIxData, InitializeMyStateIxData,
+    TransferOwnershipIxData, UpdateMyStateIxData, WithdrawIxData,
+};
+use solana_pinocchio_starter::state::{
+    load_acc, to_bytes, AccountHeader, DataLen, MyState, MyStateV1, MyStateV2, State, Vault,
+};
 use solana_pinocchio_starter::ID;
 use solana_sdk::rent::Rent;
 use solana_sdk::sysvar::Sysvar;
@@ -61,15 +68,16 @@ fn test_initialize_mystate() {
     // Create the instruction data
     let ix_data = InitializeMyStateIxData {
         owner: *PAYER.as_array(),
-        data: [1; 32],
+        data_len: 32,
         bump,
     };
 
     // Ix discriminator = 0
     let mut ser_ix_data = vec![0];
 
-    // Serialize the instruction data
+    // Serialize the instruction data, payload last
     ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    ser_ix_data.extend_from_slice(&[1; 32]);
 
     // Create instruction
     let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
@@ -82,65 +90,1037 @@ fn test_initialize_mystate() {
         (system_program, system_account.clone()),
     ];
 
-    let init_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+    let init_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&mystate_pda)
+                .space(MyState::size(32))
+                .build(),
+        ],
+    );
 
     assert!(init_res.program_result == ProgramResult::Success);
+
+    let my_state = mystate(&init_res.resulting_accounts[1].1);
+    assert_eq!(my_state.data_len, 32);
+    assert_eq!(
+        MyState::payload(&init_res.resulting_accounts[1].1.data),
+        &[1; 32]
+    );
 }
 
 #[test]
 fn test_update_mystate() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, _system_account) = program::keyed_account_for_system_program();
+    // Growing the payload from 32 to 64 bytes
+    let (instruction, tx_accounts) = update_ix(
+        &[2; 64],
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
 
-    // Create the PDA
-    let (mystate_pda, _bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    let rent_delta = mollusk.sysvars.rent.minimum_balance(MyState::size(64))
+        - mollusk.sysvars.rent.minimum_balance(MyState::size(32));
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    let update_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL - rent_delta)
+                .build(),
+            Check::account(&tx_accounts[1].0)
+                .space(MyState::size(64))
+                .build(),
+        ],
+    );
+
+    assert!(update_res.program_result == ProgramResult::Success);
+
+    let my_state = mystate(&update_res.resulting_accounts[1].1);
+    assert_eq!(my_state.state, State::Updated);
+    assert_eq!(my_state.update_count, 1);
+    assert_eq!(my_state.data_len, 64);
+    assert_eq!(
+        MyState::payload(&update_res.resulting_accounts[1].1.data),
+        &[2; 64]
+    );
+}
+
+#[test]
+fn test_update_mystate_shrink() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = update_ix(
+        &[2; 8],
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
+
+    let rent_refund = mollusk.sysvars.rent.minimum_balance(MyState::size(32))
+        - mollusk.sysvars.rent.minimum_balance(MyState::size(8));
+
+    let update_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL + rent_refund)
+                .build(),
+            Check::account(&tx_accounts[1].0)
+                .space(MyState::size(8))
+                .lamports(mollusk.sysvars.rent.minimum_balance(MyState::size(8)))
+                .build(),
+        ],
+    );
+
+    assert_eq!(
+        MyState::payload(&update_res.resulting_accounts[1].1.data),
+        &[2; 8]
+    );
+}
+
+#[test]
+fn test_update_mystate_too_long() {
+    let mollusk = mollusk();
 
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
+    let payload = vec![2; MyState::MAX_DATA_LEN + 1];
 
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
+    let (instruction, tx_accounts) = update_ix(
+        &payload,
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
 
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::DataTooLong as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_update_mystate_wrong_length_prefix() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = update_ix(
+        &[2; 16],
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
+
+    // Prefix claims more bytes than the instruction carries
+    instruction.data[1] = 17;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidInstructionData as u32,
+        ))],
+    );
+}
+
+const NEW_OWNER: Pubkey = Pubkey::new_from_array([9; 32]);
+
+fn mystate_account(state: State, pending_owner: Pubkey) -> Account {
     let my_state = MyState {
+        header: AccountHeader {
+            discriminator: MyState::DISCRIMINATOR,
+            version: MyState::VERSION,
+        },
         is_initialized: true,
         owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
+        state,
         update_count: 0,
+        pending_owner: *pending_owner.as_array(),
+        last_updated_slot: 0,
+        data_len: 32,
     };
 
-    mystate_account.data = unsafe { to_bytes(&my_state).to_vec() };
+    let rent = Rent::default().minimum_balance(MyState::size(32));
+    let mut mystate_account = Account::new(rent, MyState::size(32), &PROGRAM);
+    mystate_account.data = unsafe { to_bytes(&my_state) }.to_vec();
+    mystate_account.data.extend_from_slice(&[1; 32]);
+    mystate_account
+}
+
+fn update_ix(payload: &[u8], mystate_account: Account) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_account) = program::keyed_account_for_system_program();
+
+    let (mystate_pda, _) =
+        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
 
-    //Push the accounts in to the instruction_accounts vec!
     let ix_accounts = vec![
         AccountMeta::new(PAYER, true),
         AccountMeta::new(mystate_pda, false),
+        AccountMeta::new_readonly(system_program, false),
     ];
 
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
+    let ix_data = UpdateMyStateIxData {
+        data_len: payload.len() as u16,
+    };
 
     // Ix discriminator = 1
     let mut ser_ix_data = vec![1];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    ser_ix_data.extend_from_slice(payload);
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (mystate_pda, mystate_account),
+        (system_program, system_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+fn mystate_ix(
+    ix_disc: u8,
+    signer: Pubkey,
+    ix_data: &[u8],
+    mystate_account: Account,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    let (mystate_pda, _) =
+        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+
+    let ix_accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(mystate_pda, false),
+    ];
+
+    let mut ser_ix_data = vec![ix_disc];
+    ser_ix_data.extend_from_slice(ix_data);
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
 
-    // Serialize the instruction data
+    let tx_accounts = vec![
+        (signer, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (mystate_pda, mystate_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+fn mystate(account: &Account) -> MyState {
+    unsafe { *load_acc::<MyState>(&account.data[..MyState::LEN]).unwrap() }
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let mollusk = mollusk();
+
+    let ix_data = TransferOwnershipIxData {
+        new_owner: *NEW_OWNER.as_array(),
+    };
+
+    // Ix discriminator = 6
+    let (instruction, tx_accounts) = mystate_ix(
+        6,
+        PAYER,
+        unsafe { to_bytes(&ix_data) },
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
+
+    let propose_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let my_state = mystate(&propose_res.resulting_accounts[1].1);
+    assert_eq!(my_state.owner, *PAYER.as_array());
+    assert_eq!(my_state.pending_owner, *NEW_OWNER.as_array());
+
+    // Ix discriminator = 7
+    let (instruction, tx_accounts) = mystate_ix(
+        7,
+        NEW_OWNER,
+        &[],
+        propose_res.resulting_accounts[1].1.clone(),
+    );
+
+    let accept_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let my_state = mystate(&accept_res.resulting_accounts[1].1);
+    assert_eq!(my_state.owner, *NEW_OWNER.as_array());
+    assert_eq!(my_state.pending_owner, [0; 32]);
+}
+
+#[test]
+fn test_accept_ownership_not_pending() {
+    let mollusk = mollusk();
+
+    // Someone other than the proposed owner tries to take over
+    let (instruction, tx_accounts) = mystate_ix(
+        7,
+        Pubkey::new_unique(),
+        &[],
+        mystate_account(State::Initialized, NEW_OWNER),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidPendingOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_update_frozen_mystate() {
+    let mollusk = mollusk();
+
+    // Ix discriminator = 9
+    let (instruction, tx_accounts) = mystate_ix(
+        9,
+        PAYER,
+        &[],
+        mystate_account(State::Initialized, Pubkey::default()),
+    );
+
+    let freeze_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    assert_eq!(
+        mystate(&freeze_res.resulting_accounts[1].1).state,
+        State::Frozen
+    );
+
+    let (instruction, tx_accounts) =
+        update_ix(&[2; 32], freeze_res.resulting_accounts[1].1.clone());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::StateFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_close_mystate() {
+    let mollusk = mollusk();
+
+    let rent = Rent::default().minimum_balance(MyState::LEN);
+
+    // Ix discriminator = 8
+    let (instruction, tx_accounts) = mystate_ix(
+        8,
+        PAYER,
+        &[],
+        mystate_account(State::Updated, Pubkey::default()),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL + rent)
+                .build(),
+            Check::account(&tx_accounts[1].0).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_close_mystate_not_owner() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = mystate_ix(
+        8,
+        NEW_OWNER,
+        &[],
+        mystate_account(State::Updated, Pubkey::default()),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_freeze_mystate_foreign_account() {
+    let mollusk = mollusk();
+
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    // Same bytes as a real MyState, but the account belongs to another program
+    let mut mystate_account = mystate_account(State::Initialized, Pubkey::default());
+    mystate_account.owner = system_program;
+
+    let (instruction, tx_accounts) = mystate_ix(9, PAYER, &[], mystate_account);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+fn mystate_v1_account() -> Account {
+    let my_state = MyStateV1 {
+        is_initialized: true,
+        owner: *PAYER.as_array(),
+        state: State::Updated,
+        data: [1; 32],
+        update_count: 3,
+    };
+
+    let rent = Rent::default().minimum_balance(MyStateV1::LEN);
+    let mut mystate_account = Account::new(rent, MyStateV1::LEN, &PROGRAM);
+    mystate_account.data = unsafe { to_bytes(&my_state) }.to_vec();
+    mystate_account
+}
+
+fn migrate_ix(mystate_account: Account) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_account) = program::keyed_account_for_system_program();
+
+    let (mystate_pda, _) =
+        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+
+    let ix_accounts = vec![
+        AccountMeta::new(PAYER, true),
+        AccountMeta::new(mystate_pda, false),
+        AccountMeta::new_readonly(system_program, false),
+    ];
+
+    // Ix discriminator = 10
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[10], ix_accounts);
+
+    let tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (mystate_pda, mystate_account),
+        (system_program, system_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+#[test]
+fn test_migrate_mystate() {
+    let mollusk = mollusk();
+
+    // The layout the program was first deployed with
+    assert_eq!(MyStateV1::LEN, 72);
+
+    let (instruction, tx_accounts) = migrate_ix(mystate_v1_account());
+    let mystate_pda = tx_accounts[1].0;
+
+    let rent_delta = Rent::default().minimum_balance(MyState::size(32))
+        - Rent::default().minimum_balance(MyStateV1::LEN);
+
+    let migrate_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL - rent_delta)
+                .build(),
+            Check::account(&mystate_pda)
+                .space(MyState::size(32))
+                .build(),
+        ],
+    );
+
+    let my_state = mystate(&migrate_res.resulting_accounts[1].1);
+    assert_eq!(my_state.header.discriminator, MyState::DISCRIMINATOR);
+    assert_eq!(my_state.header.version, MyState::VERSION);
+    assert_eq!(my_state.owner, *PAYER.as_array());
+    assert_eq!(my_state.pending_owner, [0; 32]);
+    assert_eq!(my_state.state, State::Updated);
+    assert_eq!(my_state.data_len, 32);
+    assert_eq!(
+        MyState::payload(&migrate_res.resulting_accounts[1].1.data),
+        &[1; 32]
+    );
+    assert_eq!(my_state.update_count, 3);
+
+    // Migrating again leaves the account as it is
+    let remigrate_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &migrate_res.resulting_accounts,
+        &[Check::success()],
+    );
+    assert_eq!(
+        remigrate_res.resulting_accounts[1].1.data,
+        migrate_res.resulting_accounts[1].1.data
+    );
+}
+
+#[test]
+fn test_migrate_mystate_v2() {
+    let mollusk = mollusk();
+
+    let my_state = MyStateV2 {
+        header: AccountHeader {
+            discriminator: MyState::DISCRIMINATOR,
+            version: 2,
+        },
+        is_initialized: true,
+        state: State::Initialized,
+        update_count: 0,
+        owner: *PAYER.as_array(),
+        pending_owner: [0; 32],
+        data: [1; 32],
+        last_updated_slot: 42,
+    };
+
+    let rent = Rent::default().minimum_balance(MyStateV2::LEN);
+    let mut mystate_account = Account::new(rent, MyStateV2::LEN, &PROGRAM);
+    mystate_account.data = unsafe { to_bytes(&my_state) }.to_vec();
+
+    let (instruction, tx_accounts) = migrate_ix(mystate_account);
+
+    let migrate_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[1].0)
+                .space(MyState::size(32))
+                .build(),
+        ],
+    );
+
+    let my_state = mystate(&migrate_res.resulting_accounts[1].1);
+    assert_eq!(my_state.header.version, MyState::VERSION);
+    assert_eq!(my_state.last_updated_slot, 42);
+    assert_eq!(
+        MyState::payload(&migrate_res.resulting_accounts[1].1.data),
+        &[1; 32]
+    );
+}
+
+#[test]
+fn test_update_mystate_needs_migration() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = update_ix(&[2; 32], mystate_v1_account());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::AccountNeedsMigration as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_transfer_ownership_baseline_account() {
+    let mollusk = mollusk();
+
+    let ix_data = TransferOwnershipIxData {
+        new_owner: *NEW_OWNER.as_array(),
+    };
+
+    // An account created before `pending_owner` existed is refused until migrated
+    let (instruction, tx_accounts) = mystate_ix(
+        6,
+        PAYER,
+        unsafe { to_bytes(&ix_data) },
+        mystate_v1_account(),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::AccountNeedsMigration as u32,
+        ))],
+    );
+
+    let (migrate_instruction, migrate_accounts) = migrate_ix(mystate_v1_account());
+
+    let migrate_res = mollusk.process_and_validate_instruction(
+        &migrate_instruction,
+        &migrate_accounts,
+        &[Check::success()],
+    );
+
+    let (instruction, tx_accounts) = mystate_ix(
+        6,
+        PAYER,
+        unsafe { to_bytes(&ix_data) },
+        migrate_res.resulting_accounts[1].1.clone(),
+    );
+
+    let propose_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let my_state = mystate(&propose_res.resulting_accounts[1].1);
+    assert_eq!(my_state.pending_owner, *NEW_OWNER.as_array());
+    assert_eq!(
+        MyState::payload(&propose_res.resulting_accounts[1].1.data),
+        &[1; 32]
+    );
+}
+
+const UNLOCK_AT: i64 = 1_800_000_000;
+
+const BENEFICIARY: Pubkey = Pubkey::new_from_array([7; 32]);
+
+fn vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Vault::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM)
+}
+
+fn vault_rent() -> u64 {
+    Rent::default().minimum_balance(Vault::LEN)
+}
+
+fn vault_account(savings: u64, unlock_at: i64, beneficiary: Pubkey) -> Account {
+    let vault = Vault {
+        owner: *PAYER.as_array(),
+        beneficiary: *beneficiary.as_array(),
+        unlock_at,
+        is_initialized: true,
+    };
+
+    let mut vault_account = Account::new(vault_rent() + savings, Vault::LEN, &PROGRAM);
+    vault_account.data = unsafe { to_bytes(&vault) }.to_vec();
+    vault_account
+}
+
+fn vault_state(account: &Account) -> Vault {
+    unsafe { *load_acc::<Vault>(&account.data).unwrap() }
+}
+
+fn deposit_ix(
+    amount: u64,
+    unlock_at: i64,
+    vault_account: Account,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_account) = program::keyed_account_for_system_program();
+
+    let (vault_pda, bump) = vault_pda();
+
+    let ix_accounts = vec![
+        AccountMeta::new(PAYER, true),
+        AccountMeta::new(vault_pda, false),
+        AccountMeta::new_readonly(system_program, false),
+    ];
+
+    let ix_data = DepositIxData {
+        amount,
+        unlock_at,
+        beneficiary: *BENEFICIARY.as_array(),
+        bump,
+    };
+
+    // Ix discriminator = 2
+    let mut ser_ix_data = vec![2];
     ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
 
-    // Create instruction
     let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
+
+    let tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (vault_pda, vault_account),
+        (system_program, system_account),
     ];
 
-    let update_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+    (instruction, tx_accounts)
+}
 
-    assert!(update_res.program_result == ProgramResult::Success);
+fn withdraw_ix(amount: u64, beneficiary: Pubkey) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    let (vault_pda, bump) = vault_pda();
+
+    // No beneficiary pays out to the owner
+    let recipient = if beneficiary == Pubkey::default() {
+        PAYER
+    } else {
+        beneficiary
+    };
+
+    let ix_accounts = vec![
+        AccountMeta::new(PAYER, true),
+        AccountMeta::new(vault_pda, false),
+        AccountMeta::new(recipient, false),
+    ];
+
+    let ix_data = WithdrawIxData { amount, bump };
+
+    // Ix discriminator = 3
+    let mut ser_ix_data = vec![3];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let mut tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (
+            vault_pda,
+            vault_account(2 * LAMPORTS_PER_SOL, UNLOCK_AT, beneficiary),
+        ),
+    ];
+
+    if recipient != PAYER {
+        tx_accounts.push((recipient, Account::new(0, 0, &system_program)));
+    }
+
+    (instruction, tx_accounts)
+}
+
+fn close_vault_ix(beneficiary: Pubkey) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    let (vault_pda, bump) = vault_pda();
+
+    let ix_accounts = vec![
+        AccountMeta::new(PAYER, true),
+        AccountMeta::new(vault_pda, false),
+        AccountMeta::new(beneficiary, false),
+    ];
+
+    let ix_data = CloseVaultIxData { bump };
+
+    // Ix discriminator = 4
+    let mut ser_ix_data = vec![4];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (
+            vault_pda,
+            vault_account(LAMPORTS_PER_SOL, UNLOCK_AT, beneficiary),
+        ),
+        (beneficiary, Account::new(0, 0, &system_program)),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+fn extend_lock_ix(unlock_at: i64) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    let (vault_pda, _) = vault_pda();
+
+    let ix_accounts = vec![
+        AccountMeta::new(PAYER, true),
+        AccountMeta::new(vault_pda, false),
+    ];
+
+    let ix_data = ExtendLockIxData { unlock_at };
+
+    // Ix discriminator = 5
+    let mut ser_ix_data = vec![5];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+        (
+            vault_pda,
+            vault_account(LAMPORTS_PER_SOL, UNLOCK_AT, Pubkey::default()),
+        ),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+#[test]
+fn test_deposit() {
+    let mollusk = mollusk();
+
+    let (system_program, _) = program::keyed_account_for_system_program();
+    let (vault_pda, _) = vault_pda();
+
+    let (instruction, tx_accounts) = deposit_ix(
+        LAMPORTS_PER_SOL / 2,
+        UNLOCK_AT,
+        Account::new(0, 0, &system_program),
+    );
+
+    let deposit_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL / 2 - vault_rent())
+                .build(),
+            Check::account(&vault_pda)
+                .owner(&PROGRAM)
+                .space(Vault::LEN)
+                .lamports(vault_rent() + LAMPORTS_PER_SOL / 2)
+                .build(),
+        ],
+    );
+
+    let vault = vault_state(&deposit_res.resulting_accounts[1].1);
+    assert_eq!(vault.owner, *PAYER.as_array());
+    assert_eq!(vault.beneficiary, *BENEFICIARY.as_array());
+    assert_eq!(vault.unlock_at, UNLOCK_AT);
+}
+
+#[test]
+fn test_deposit_prefunded_vault() {
+    let mollusk = mollusk();
+
+    let (system_program, _) = program::keyed_account_for_system_program();
+    let (vault_pda, _) = vault_pda();
+
+    // Lamports sent to the vault address before the first deposit
+    let (instruction, tx_accounts) = deposit_ix(
+        LAMPORTS_PER_SOL / 2,
+        UNLOCK_AT,
+        Account::new(1, 0, &system_program),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&vault_pda)
+                .owner(&PROGRAM)
+                .space(Vault::LEN)
+                .lamports(vault_rent() + LAMPORTS_PER_SOL / 2)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn test_deposit_existing_vault() {
+    let mollusk = mollusk();
+
+    let (vault_pda, _) = vault_pda();
+
+    // A top-up asking for an earlier unlock leaves the lock alone
+    let (instruction, tx_accounts) = deposit_ix(
+        LAMPORTS_PER_SOL / 2,
+        0,
+        vault_account(LAMPORTS_PER_SOL, UNLOCK_AT, Pubkey::default()),
+    );
+
+    let deposit_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL / 2)
+                .build(),
+            Check::account(&vault_pda)
+                .lamports(vault_rent() + LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 2)
+                .build(),
+        ],
+    );
+
+    let vault = vault_state(&deposit_res.resulting_accounts[1].1);
+    assert_eq!(vault.unlock_at, UNLOCK_AT);
+    assert_eq!(vault.beneficiary, [0; 32]);
+}
+
+#[test]
+fn test_withdraw() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    let (instruction, tx_accounts) = withdraw_ix(LAMPORTS_PER_SOL, Pubkey::default());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(2 * LAMPORTS_PER_SOL)
+                .build(),
+            Check::account(&tx_accounts[1].0)
+                .lamports(vault_rent() + LAMPORTS_PER_SOL)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn test_withdraw_keeps_rent() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    // Everything but the rent-exempt minimum can come out
+    let (instruction, tx_accounts) = withdraw_ix(2 * LAMPORTS_PER_SOL, Pubkey::default());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[1].0)
+                .lamports(vault_rent())
+                .build(),
+        ],
+    );
+
+    // One lamport more would dip into it
+    let (instruction, tx_accounts) = withdraw_ix(2 * LAMPORTS_PER_SOL + 1, Pubkey::default());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::InsufficientFunds)],
+    );
+}
+
+#[test]
+fn test_withdraw_system_owned_vault() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    let (system_program, _) = program::keyed_account_for_system_program();
+
+    let (instruction, mut tx_accounts) = withdraw_ix(LAMPORTS_PER_SOL, Pubkey::default());
+
+    tx_accounts[1].1 = Account::new(2 * LAMPORTS_PER_SOL, 0, &system_program);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn test_withdraw_locked() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT - 1;
+
+    let (instruction, tx_accounts) = withdraw_ix(LAMPORTS_PER_SOL, Pubkey::default());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::VaultLocked as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_withdraw_to_beneficiary() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    let (instruction, tx_accounts) = withdraw_ix(LAMPORTS_PER_SOL, BENEFICIARY);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER).lamports(LAMPORTS_PER_SOL).build(),
+            Check::account(&BENEFICIARY)
+                .lamports(LAMPORTS_PER_SOL)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn test_withdraw_wrong_beneficiary() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    let (mut instruction, mut tx_accounts) = withdraw_ix(LAMPORTS_PER_SOL, BENEFICIARY);
+
+    // The owner can't redirect a vault that pays out to a beneficiary
+    instruction.accounts[2].pubkey = PAYER;
+    tx_accounts.pop();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidBeneficiary as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_close_vault() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT;
+
+    let (instruction, tx_accounts) = close_vault_ix(BENEFICIARY);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&PAYER)
+                .lamports(LAMPORTS_PER_SOL + vault_rent())
+                .build(),
+            Check::account(&BENEFICIARY)
+                .lamports(LAMPORTS_PER_SOL)
+                .build(),
+            Check::account(&tx_accounts[1].0).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_close_vault_locked() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = UNLOCK_AT - 1;
+
+    let (instruction, tx_accounts) = close_vault_ix(BENEFICIARY);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::VaultLocked as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_extend_lock() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = extend_lock_ix(UNLOCK_AT + 86_400);
+
+    let extend_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let vault = vault_state(&extend_res.resulting_accounts[1].1);
+    assert_eq!(vault.unlock_at, UNLOCK_AT + 86_400);
+}
+
+#[test]
+fn test_extend_lock_shorten() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = extend_lock_ix(UNLOCK_AT - 1);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::LockShortened as u32,
+        ))],
+    );
 }
@@ -10,6 +10,8 @@ pub enum MyProgramError {
     PdaMismatch,
     // Invalid Owner
     InvalidOwner,
+    // Token account or mint doesn't match the escrow
+    InvalidMint,
 }
 
 impl From<MyProgramError> for ProgramError {
@@ -3,7 +3,7 @@ use pinocchio::{
     instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvars::rent::Rent,
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 
@@ -22,6 +22,8 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MakeEscrowIxData {
+    pub maker: Pubkey,
+    pub amount: u64,
     pub receive_amount: u64,
     pub bump: u8,
 }
@@ -37,9 +39,10 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         mint_b,
         escrow,
         vault,
-        maker_ata_x,
+        maker_ata_a,
         _sysvar_rent_acc,
         _system_program,
+        _token_program,
         _rest @..
         ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -53,20 +56,39 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(ProgramError::AccountAlreadyInitialized);
     }
 
-    let vault_acc = TokenAccount::from_account_info(vault)?;
-    assert_eq!(vault_acc.owner(), escrow.key());
-
-    let maker_ata_x_acc = TokenAccount::from_account_info(maker_ata_x)?;
-    assert_eq!(maker_ata_x_acc.owner(), maker.key());
-
-    let rent = Rent::get()?;
-
     let ix_data = unsafe { load_ix_data::<MakeEscrowIxData>(data)? };
 
     if ix_data.maker.ne(maker.key()) {
         return Err(MyProgramError::InvalidOwner.into());
     }
 
+    // The token account borrows have to end before the CPIs below
+    {
+        let maker_ata_a_acc = TokenAccount::from_account_info(maker_ata_a)?;
+        if maker_ata_a_acc.owner().ne(maker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if maker_ata_a_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        // The vault is the escrow PDA's token account for mint A, created by the client
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(escrow.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+    }
+
+    // Make sure mint B is a real mint before recording it
+    Mint::from_account_info(mint_b)?;
+
+    let decimals = Mint::from_account_info(mint_a)?.decimals();
+
+    let rent = Rent::get()?;
+
     let pda_bump_bytes = [ix_data.bump];
 
     Escrow::validate_pda(ix_data.bump, escrow.key(), &ix_data.maker)?;
@@ -78,7 +100,7 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         Seed::from(&pda_bump_bytes[..]),
     ];
     let signers = [Signer::from(&signer_seeds[..])];
-    // Create the governance config account
+    // Create the escrow account
     (CreateAccount {
         from: maker,
         to: escrow,
@@ -89,15 +111,15 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     .invoke_signed(&signers)?;
 
     // Initialize the escrow account
-    Escrow::make(escrow, ix_data)?;
+    Escrow::make(escrow, ix_data, mint_a.key(), mint_b.key())?;
 
     (TransferChecked {
-        from: maker_ata_x,
+        from: maker_ata_a,
         to: vault,
         authority: maker,
         mint: mint_a,
-        amount: ix_data.receive_amount,
-        decimals: mint_a.decimals,
+        amount: ix_data.amount,
+        decimals,
     }).invoke()?;
 
     Ok(())
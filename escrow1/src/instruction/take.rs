@@ -1,107 +1,134 @@
-
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::Pubkey,
-    sysvars::rent::Rent,
     ProgramResult,
 };
 
-use pinocchio_system::instructions::CreateAccount;
-
-use pinocchio_token::{ instructions::TransferChecked, state::{ Mint, TokenAccount } };
+use pinocchio_token::{
+    instructions::{CloseAccount, TransferChecked},
+    state::{Mint, TokenAccount},
+};
 
 use crate::{
     error::MyProgramError,
-    state::{
-        utils::{load_ix_data, DataLen},
-        Escrow,
-    },
+    state::{utils::load_acc, Escrow},
 };
 
-pub fn process_take_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+pub fn process_take_escrow(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     let [
-        maker,
         taker,
+        maker,
         mint_a,
         mint_b,
         escrow,
         vault,
-        maker_ata_x,
-        maker_ata_y,
-        taker_ata_x,
-        taker_ata_y,
-        _sysvar_rent_acc,
+        taker_ata_a,
+        taker_ata_b,
+        maker_ata_b,
         _system_program,
+        _token_program,
         _rest @..
         ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    if !maker.is_signer() {
+    if !taker.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    if !escrow.data_is_empty() {
-        return Err(ProgramError::AccountAlreadyInitialized);
+    if !escrow.is_owned_by(&crate::ID) {
+        return Err(ProgramError::IllegalOwner);
     }
 
-    let vault_acc = TokenAccount::from_account_info(vault)?;
-    assert_eq!(vault_acc.owner(), escrow.key());
-
-    let maker_ata_acc = TokenAccount::from_account_info(maker_ata_x)?;
-    assert_eq!(maker_ata_acc.owner(), maker.key());
-
-    let maker_ata_acc = TokenAccount::from_account_info(maker_ata_y)?;
-    assert_eq!(maker_ata_acc.owner(), maker.key());
-
-    let taker_ata_acc = TokenAccount::from_account_info(taker_ata_x)?;
-    assert_eq!(taker_ata_acc.owner(), taker.key());
-
-    let taker_ata_acc = TokenAccount::from_account_info(taker_ata_y)?;
-    assert_eq!(taker_ata_acc.owner(), taker.key());
+    let escrow_state = unsafe { *load_acc::<Escrow>(&escrow.try_borrow_data()?)? };
 
-    let rent = Rent::get()?;
-
-    let ix_data = unsafe { load_ix_data::<MakeEscrowIxData>(data)? };
-
-    if ix_data.maker.ne(maker.key()) {
+    if escrow_state.maker.ne(maker.key()) {
         return Err(MyProgramError::InvalidOwner.into());
     }
+    if escrow_state.mint_a.ne(mint_a.key()) || escrow_state.mint_b.ne(mint_b.key()) {
+        return Err(MyProgramError::InvalidMint.into());
+    }
 
-    let pda_bump_bytes = [ix_data.bump];
+    Escrow::validate_pda(escrow_state.bump, escrow.key(), maker.key())?;
+
+    let vault_amount = {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(escrow.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let taker_ata_a_acc = TokenAccount::from_account_info(taker_ata_a)?;
+        if taker_ata_a_acc.owner().ne(taker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if taker_ata_a_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let taker_ata_b_acc = TokenAccount::from_account_info(taker_ata_b)?;
+        if taker_ata_b_acc.owner().ne(taker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if taker_ata_b_acc.mint().ne(mint_b.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let maker_ata_b_acc = TokenAccount::from_account_info(maker_ata_b)?;
+        if maker_ata_b_acc.owner().ne(maker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if maker_ata_b_acc.mint().ne(mint_b.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        vault_acc.amount()
+    };
 
-    Escrow::validate_pda(ix_data.bump, escrow.key(), &ix_data.maker)?;
+    let decimals_a = Mint::from_account_info(mint_a)?.decimals();
+    let decimals_b = Mint::from_account_info(mint_b)?.decimals();
+
+    // Pay the maker in mint B
+    (TransferChecked {
+        from: taker_ata_b,
+        to: maker_ata_b,
+        authority: taker,
+        mint: mint_b,
+        amount: escrow_state.receive_amount,
+        decimals: decimals_b,
+    }).invoke()?;
+
+    let pda_bump_bytes = [escrow_state.bump];
 
     // Signer seeds
     let signer_seeds = [
         Seed::from(Escrow::SEED.as_bytes()),
-        Seed::from(&ix_data.maker),
+        Seed::from(maker.key()),
         Seed::from(&pda_bump_bytes[..]),
     ];
     let signers = [Signer::from(&signer_seeds[..])];
-    // Create the governance config account
-    (CreateAccount {
-        from: maker,
-        to: escrow,
-        space: Escrow::LEN as u64,
-        owner: &crate::ID,
-        lamports: rent.minimum_balance(Escrow::LEN),
-    })
-    .invoke_signed(&signers)?;
-
-    // Initialize the escrow account
-    Escrow::make(escrow, ix_data)?;
 
+    // Release the vault to the taker
     (TransferChecked {
-        from: maker_ata,
-        to: vault,
-        authority: maker,
+        from: vault,
+        to: taker_ata_a,
+        authority: escrow,
         mint: mint_a,
-        amount: ix_data.receive_amount,
-        decimals: mint_a.decimals,
-    }).invoke()?;
+        amount: vault_amount,
+        decimals: decimals_a,
+    }).invoke_signed(&signers)?;
+
+    (CloseAccount {
+        account: vault,
+        destination: maker,
+        authority: escrow,
+    }).invoke_signed(&signers)?;
+
+    // Close the escrow account, rent goes back to the maker
+    Escrow::take(escrow, maker)?;
 
     Ok(())
 }
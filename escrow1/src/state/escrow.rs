@@ -47,13 +47,15 @@ impl Escrow {
     pub fn make(
         escrow_acc: &AccountInfo,
         ix_data: &MakeEscrowIxData,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
     ) -> ProgramResult {
         let escrow =
             unsafe { load_acc_mut_unchecked::<Escrow>(escrow_acc.borrow_mut_data_unchecked()) }?;
 
         escrow.maker = ix_data.maker;
-        escrow.mint_a = ix_data.mint_a;
-        escrow.mint_b = ix_data.mint_b;
+        escrow.mint_a = *mint_a;
+        escrow.mint_b = *mint_b;
         escrow.receive_amount = ix_data.receive_amount;
         escrow.bump = ix_data.bump;
         escrow.is_initialized = true;
@@ -61,13 +63,22 @@ impl Escrow {
         Ok(())
     }
 
+    /// Closes the escrow account, sending its rent to `destination`.
     pub fn take(
         escrow_acc: &AccountInfo,
-        ix_data: &TakeEscrowIxData,
+        destination: &AccountInfo,
     ) -> ProgramResult {
-        let escrow =
-            unsafe { load_acc_mut_unchecked::<Escrow>(escrow_acc.borrow_mut_data_unchecked()) }?;
+        {
+            let mut data = escrow_acc.try_borrow_mut_data()?;
+            data.fill(0);
+        }
 
+        let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+        *destination_lamports = destination_lamports
+            .checked_add(escrow_acc.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        *escrow_acc.try_borrow_mut_lamports()? = 0;
 
+        Ok(())
     }
 }
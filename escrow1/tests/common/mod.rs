@@ -0,0 +1,169 @@
+#![allow(dead_code)]
+
+#[path = "../../../test_fixtures/token.rs"]
+mod token;
+pub use token::*;
+
+use mollusk_svm::{program, Mollusk};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+
+use solana_pinocchio_starter::instruction::MakeEscrowIxData;
+use solana_pinocchio_starter::state::{to_bytes, DataLen, Escrow};
+use solana_pinocchio_starter::ID;
+
+pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+
+/// Escrow program with the SPL Token program loaded from `tests/elfs`.
+pub fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
+    mollusk.add_program(
+        &TOKEN_PROGRAM,
+        "tests/elfs/spl_token",
+        &mollusk_svm::program::loader_keys::LOADER_V3,
+    );
+    mollusk
+}
+
+pub const MAKER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+
+pub const TAKER: Pubkey = pubkey!("8GRj9cW4cfWCbz4hZ5jRc4ifWJ8VQHk6pbKSx5zKkXgH");
+
+pub const MINT_A: Pubkey = pubkey!("7sQ4dZxmG3aTqzjfLVQFNqHuJjd1ZGJG6VjC9v6dHt4G");
+
+pub const MINT_B: Pubkey = pubkey!("4ZJkt7Cym6LxzjH8wd2Cg6vCTM4Hw6vGpXo9Pa3qHyDr");
+
+pub const VAULT: Pubkey = pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+
+pub const OFFER_AMOUNT: u64 = 5_000_000;
+
+pub const RECEIVE_AMOUNT: u64 = 1_000_000;
+
+pub fn escrow_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Escrow::SEED.as_bytes(), MAKER.as_ref()], &PROGRAM)
+}
+
+pub fn escrow_account(mollusk: &Mollusk) -> (Pubkey, Account) {
+    let (escrow_pda, bump) = escrow_pda();
+
+    let escrow = Escrow {
+        maker: *MAKER.as_array(),
+        mint_a: *MINT_A.as_array(),
+        mint_b: *MINT_B.as_array(),
+        receive_amount: RECEIVE_AMOUNT,
+        bump,
+        is_initialized: true,
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(Escrow::LEN),
+        Escrow::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&escrow).to_vec() };
+
+    (escrow_pda, account)
+}
+
+/// Take of the standard escrow, paid in `mint_b`.
+pub fn take(mollusk: &Mollusk, mint_b: Pubkey) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow_pda, escrow_account) = escrow_account(mollusk);
+
+    let taker_ata_a = Pubkey::new_unique();
+    let taker_ata_b = Pubkey::new_unique();
+    let maker_ata_b = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(TAKER, true),
+        AccountMeta::new(MAKER, false),
+        AccountMeta::new_readonly(MINT_A, false),
+        AccountMeta::new_readonly(mint_b, false),
+        AccountMeta::new(escrow_pda, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new(taker_ata_a, false),
+        AccountMeta::new(taker_ata_b, false),
+        AccountMeta::new(maker_ata_b, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Ix discriminator = 1, take has no instruction data
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[1], ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (TAKER, system_account(LAMPORTS_PER_SOL)),
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (MINT_A, mint_account(mollusk, &MAKER, 6)),
+        (mint_b, mint_account(mollusk, &MAKER, 6)),
+        (escrow_pda, escrow_account),
+        (VAULT, token_account(mollusk, &MINT_A, &escrow_pda, OFFER_AMOUNT)),
+        (taker_ata_a, token_account(mollusk, &MINT_A, &TAKER, 0)),
+        (taker_ata_b, token_account(mollusk, &mint_b, &TAKER, RECEIVE_AMOUNT)),
+        (maker_ata_b, token_account(mollusk, &mint_b, &MAKER, 0)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// MakeEscrow depositing `OFFER_AMOUNT` of `MINT_A` for `RECEIVE_AMOUNT` of `MINT_B`.
+pub fn make(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow_pda, bump) = escrow_pda();
+    let maker_ata_a = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(MINT_A, false),
+        AccountMeta::new_readonly(MINT_B, false),
+        AccountMeta::new(escrow_pda, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new(maker_ata_a, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Create the instruction data
+    let ix_data = MakeEscrowIxData {
+        maker: *MAKER.as_array(),
+        amount: OFFER_AMOUNT,
+        receive_amount: RECEIVE_AMOUNT,
+        bump,
+    };
+
+    // Ix discriminator = 0
+    let mut ser_ix_data = vec![0];
+
+    // Serialize the instruction data
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    // Create instruction
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (MAKER, system_account(10 * LAMPORTS_PER_SOL)),
+        (MINT_A, mint_account(mollusk, &MAKER, 6)),
+        (MINT_B, mint_account(mollusk, &MAKER, 6)),
+        (escrow_pda, system_account(0)),
+        (VAULT, token_account(mollusk, &MINT_A, &escrow_pda, 0)),
+        (maker_ata_a, token_account(mollusk, &MINT_A, &MAKER, OFFER_AMOUNT)),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
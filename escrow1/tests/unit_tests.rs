@@ -1,146 +1,123 @@
+mod common;
+
+use common::*;
 use mollusk_svm::result::{Check, ProgramResult};
-use mollusk_svm::{program, Mollusk};
-use solana_sdk::account::Account;
-use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use solana_sdk::pubkey;
+use solana_sdk::program_error::ProgramError;
 use solana_sdk::pubkey::Pubkey;
-extern crate alloc;
-use alloc::vec;
 
-use solana_pinocchio_starter::instruction::{InitializeMyStateIxData, UpdateMyStateIxData};
-use solana_pinocchio_starter::state::{to_bytes, DataLen, MyState, State};
-use solana_pinocchio_starter::ID;
-use solana_sdk::rent::Rent;
-use solana_sdk::sysvar::Sysvar;
+use solana_pinocchio_starter::error::MyProgramError;
+use solana_pinocchio_starter::state::{load_acc, DataLen, Escrow};
 
-pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+#[test]
+fn test_make_escrow() {
+    let mollusk = mollusk();
 
-pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+    let (instruction, tx_accounts) = make(&mollusk);
 
-pub const PAYER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+    let (escrow_pda, bump) = escrow_pda();
 
-pub fn mollusk() -> Mollusk {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
-    mollusk
-}
+    let make_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow_pda).owner(&PROGRAM).space(Escrow::LEN).build(),
+        ],
+    );
 
-pub fn get_rent_data() -> Vec<u8> {
-    let rent = Rent::default();
-    unsafe {
-        core::slice::from_raw_parts(&rent as *const Rent as *const u8, Rent::size_of()).to_vec()
-    }
+    assert_eq!(token_amount(&make_res.resulting_accounts[4].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&make_res.resulting_accounts[5].1), 0);
+
+    let escrow = unsafe { *load_acc::<Escrow>(&make_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(escrow.maker, *MAKER.as_array());
+    assert_eq!(escrow.mint_a, *MINT_A.as_array());
+    assert_eq!(escrow.mint_b, *MINT_B.as_array());
+    assert_eq!(escrow.receive_amount, RECEIVE_AMOUNT);
+    assert_eq!(escrow.bump, bump);
 }
 
 #[test]
-fn test_initialize_mystate() {
+fn test_take_escrow() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, system_account) = program::keyed_account_for_system_program();
-
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
-
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
-    let mystate_account = Account::new(0, 0, &system_program);
-    let min_balance = mollusk.sysvars.rent.minimum_balance(Rent::size_of());
-    let mut rent_account = Account::new(min_balance, Rent::size_of(), &RENT);
-    rent_account.data = get_rent_data();
-
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-        AccountMeta::new_readonly(RENT, false),
-        AccountMeta::new_readonly(system_program, false),
-    ];
-
-    // Create the instruction data
-    let ix_data = InitializeMyStateIxData {
-        owner: *PAYER.as_array(),
-        data: [1; 32],
-        bump,
-    };
-
-    // Ix discriminator = 0
-    let mut ser_ix_data = vec![0];
-
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
-
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-        (RENT, rent_account.clone()),
-        (system_program, system_account.clone()),
-    ];
-
-    let init_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
-
-    assert!(init_res.program_result == ProgramResult::Success);
+    let (instruction, tx_accounts) = take(&mollusk, MINT_B);
+
+    let escrow_rent = tx_accounts[4].1.lamports;
+    let vault_rent = tx_accounts[5].1.lamports;
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[4].0).lamports(0).build(),
+            Check::account(&VAULT).lamports(0).build(),
+            Check::account(&MAKER).lamports(LAMPORTS_PER_SOL + escrow_rent + vault_rent).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&take_res.resulting_accounts[7].1), 0);
+    assert_eq!(token_amount(&take_res.resulting_accounts[8].1), RECEIVE_AMOUNT);
 }
 
 #[test]
-fn test_update_mystate() {
+fn test_take_escrow_wrong_mint() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, _system_account) = program::keyed_account_for_system_program();
+    // Pays with a mint other than the one the escrow asked for
+    let (instruction, tx_accounts) = take(&mollusk, Pubkey::new_unique());
 
-    // Create the PDA
-    let (mystate_pda, _bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidMint as u32))],
+    );
+}
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+#[test]
+fn test_take_escrow_wrong_owner() {
+    let mollusk = mollusk();
 
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
+    let (instruction, mut tx_accounts) = take(&mollusk, MINT_B);
 
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
+    // maker_ata_b belongs to the taker instead of the maker
+    tx_accounts[8].1 = token_account(&mollusk, &MINT_B, &TAKER, 0);
 
-    let my_state = MyState {
-        is_initialized: true,
-        owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
-        update_count: 0,
-    };
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidOwner as u32))],
+    );
+}
 
-    mystate_account.data = unsafe { to_bytes(&my_state).to_vec() };
+#[test]
+fn test_take_escrow_insufficient_balance() {
+    let mollusk = mollusk();
 
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-    ];
+    let (instruction, mut tx_accounts) = take(&mollusk, MINT_B);
 
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
+    tx_accounts[7].1 = token_account(&mollusk, &MINT_B, &TAKER, RECEIVE_AMOUNT - 1);
 
-    // Ix discriminator = 1
-    let mut ser_ix_data = vec![1];
+    // spl-token TokenError::InsufficientFunds
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(1))],
+    );
+}
+
+#[test]
+fn test_take_escrow_twice() {
+    let mollusk = mollusk();
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    let (instruction, tx_accounts) = take(&mollusk, MINT_B);
 
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-    ];
+    let take_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
 
-    let update_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+    let retake_res = mollusk.process_instruction(&instruction, &take_res.resulting_accounts);
 
-    assert!(update_res.program_result == ProgramResult::Success);
+    assert!(retake_res.program_result != ProgramResult::Success);
 }
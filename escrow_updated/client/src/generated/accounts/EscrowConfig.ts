@@ -8,66 +8,62 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
-import { State, stateBeet } from '../types/State'
 
 /**
- * Arguments used to create {@link MyState}
+ * Arguments used to create {@link EscrowConfig}
  * @category Accounts
  * @category generated
  */
-export type MyStateArgs = {
-  isInitialized: number
-  owner: web3.PublicKey
-  state: State
-  data: number[] /* size: 32 */
-  updateCount: number
+export type EscrowConfigArgs = {
+  admin: web3.PublicKey
+  feeRecipient: web3.PublicKey
+  feeBps: number
   bump: number
+  padding: number[] /* size: 1 */
 }
 /**
- * Holds the data for the {@link MyState} Account and provides de/serialization
+ * Holds the data for the {@link EscrowConfig} Account and provides de/serialization
  * functionality for that data
  *
  * @category Accounts
  * @category generated
  */
-export class MyState implements MyStateArgs {
+export class EscrowConfig implements EscrowConfigArgs {
   private constructor(
-    readonly isInitialized: number,
-    readonly owner: web3.PublicKey,
-    readonly state: State,
-    readonly data: number[] /* size: 32 */,
-    readonly updateCount: number,
-    readonly bump: number
+    readonly admin: web3.PublicKey,
+    readonly feeRecipient: web3.PublicKey,
+    readonly feeBps: number,
+    readonly bump: number,
+    readonly padding: number[] /* size: 1 */
   ) {}
 
   /**
-   * Creates a {@link MyState} instance from the provided args.
+   * Creates a {@link EscrowConfig} instance from the provided args.
    */
-  static fromArgs(args: MyStateArgs) {
-    return new MyState(
-      args.isInitialized,
-      args.owner,
-      args.state,
-      args.data,
-      args.updateCount,
-      args.bump
+  static fromArgs(args: EscrowConfigArgs) {
+    return new EscrowConfig(
+      args.admin,
+      args.feeRecipient,
+      args.feeBps,
+      args.bump,
+      args.padding
     )
   }
 
   /**
-   * Deserializes the {@link MyState} from the data of the provided {@link web3.AccountInfo}.
+   * Deserializes the {@link EscrowConfig} from the data of the provided {@link web3.AccountInfo}.
    * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
    */
   static fromAccountInfo(
     accountInfo: web3.AccountInfo<Buffer>,
     offset = 0
-  ): [MyState, number] {
-    return MyState.deserialize(accountInfo.data, offset)
+  ): [EscrowConfig, number] {
+    return EscrowConfig.deserialize(accountInfo.data, offset)
   }
 
   /**
    * Retrieves the account info from the provided address and deserializes
-   * the {@link MyState} from its data.
+   * the {@link EscrowConfig} from its data.
    *
    * @throws Error if no account info is found at the address or if deserialization fails
    */
@@ -75,15 +71,15 @@ export class MyState implements MyStateArgs {
     connection: web3.Connection,
     address: web3.PublicKey,
     commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
-  ): Promise<MyState> {
+  ): Promise<EscrowConfig> {
     const accountInfo = await connection.getAccountInfo(
       address,
       commitmentOrConfig
     )
     if (accountInfo == null) {
-      throw new Error(`Unable to find MyState account at ${address}`)
+      throw new Error(`Unable to find EscrowConfig account at ${address}`)
     }
-    return MyState.fromAccountInfo(accountInfo, 0)[0]
+    return EscrowConfig.fromAccountInfo(accountInfo, 0)[0]
   }
 
   /**
@@ -97,36 +93,36 @@ export class MyState implements MyStateArgs {
       'ENrRns55VechXJiq4bMbdx7idzQh7tvaEJoYeWxRNe7Y'
     )
   ) {
-    return beetSolana.GpaBuilder.fromStruct(programId, myStateBeet)
+    return beetSolana.GpaBuilder.fromStruct(programId, escrowConfigBeet)
   }
 
   /**
-   * Deserializes the {@link MyState} from the provided data Buffer.
+   * Deserializes the {@link EscrowConfig} from the provided data Buffer.
    * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
    */
-  static deserialize(buf: Buffer, offset = 0): [MyState, number] {
-    return myStateBeet.deserialize(buf, offset)
+  static deserialize(buf: Buffer, offset = 0): [EscrowConfig, number] {
+    return escrowConfigBeet.deserialize(buf, offset)
   }
 
   /**
-   * Serializes the {@link MyState} into a Buffer.
+   * Serializes the {@link EscrowConfig} into a Buffer.
    * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
    */
   serialize(): [Buffer, number] {
-    return myStateBeet.serialize(this)
+    return escrowConfigBeet.serialize(this)
   }
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link MyState}
+   * {@link EscrowConfig}
    */
   static get byteSize() {
-    return myStateBeet.byteSize
+    return escrowConfigBeet.byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
-   * {@link MyState} data from rent
+   * {@link EscrowConfig} data from rent
    *
    * @param connection used to retrieve the rent exemption information
    */
@@ -135,31 +131,30 @@ export class MyState implements MyStateArgs {
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      MyState.byteSize,
+      EscrowConfig.byteSize,
       commitment
     )
   }
 
   /**
    * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link MyState} data.
+   * hold {@link EscrowConfig} data.
    */
   static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === MyState.byteSize
+    return buf.byteLength - offset === EscrowConfig.byteSize
   }
 
   /**
-   * Returns a readable version of {@link MyState} properties
+   * Returns a readable version of {@link EscrowConfig} properties
    * and can be used to convert to JSON and/or logging
    */
   pretty() {
     return {
-      isInitialized: this.isInitialized,
-      owner: this.owner.toBase58(),
-      state: 'State.' + State[this.state],
-      data: this.data,
-      updateCount: this.updateCount,
+      admin: this.admin.toBase58(),
+      feeRecipient: this.feeRecipient.toBase58(),
+      feeBps: this.feeBps,
       bump: this.bump,
+      padding: this.padding,
     }
   }
 }
@@ -168,15 +163,14 @@ export class MyState implements MyStateArgs {
  * @category Accounts
  * @category generated
  */
-export const myStateBeet = new beet.BeetStruct<MyState, MyStateArgs>(
+export const escrowConfigBeet = new beet.BeetStruct<EscrowConfig, EscrowConfigArgs>(
   [
-    ['isInitialized', beet.u8],
-    ['owner', beetSolana.publicKey],
-    ['state', stateBeet],
-    ['data', beet.uniformFixedSizeArray(beet.u8, 32)],
-    ['updateCount', beet.u32],
+    ['admin', beetSolana.publicKey],
+    ['feeRecipient', beetSolana.publicKey],
+    ['feeBps', beet.u16],
     ['bump', beet.u8],
+    ['padding', beet.uniformFixedSizeArray(beet.u8, 1)],
   ],
-  MyState.fromArgs,
-  'MyState'
+  EscrowConfig.fromArgs,
+  'EscrowConfig'
 )
@@ -1,5 +1,11 @@
-export * from './MyState'
+export * from './BasketEscrow'
+export * from './EscrowConfig'
+export * from './EscrowState'
+export * from './OfferBook'
 
-import { MyState } from './MyState'
+import { BasketEscrow } from './BasketEscrow'
+import { EscrowConfig } from './EscrowConfig'
+import { EscrowState } from './EscrowState'
+import { OfferBook } from './OfferBook'
 
-export const accountProviders = { MyState }
+export const accountProviders = { BasketEscrow, EscrowConfig, EscrowState, OfferBook }
@@ -7,82 +7,80 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
-import {
-  InitializeMyStateIxData,
-  initializeMyStateIxDataBeet,
-} from '../types/InitializeMyStateIxData'
+import { MakeBasketIxData, makeBasketIxDataBeet } from '../types/MakeBasketIxData'
 
 /**
  * @category Instructions
- * @category InitializeState
+ * @category MakeBasket
  * @category generated
  */
-export type InitializeStateInstructionArgs = {
-  initializeMyStateIxData: InitializeMyStateIxData
+export type MakeBasketInstructionArgs = {
+  makeBasketIxData: MakeBasketIxData
 }
 /**
  * @category Instructions
- * @category InitializeState
+ * @category MakeBasket
  * @category generated
  */
-export const InitializeStateStruct = new beet.BeetArgsStruct<
-  InitializeStateInstructionArgs & {
+export const MakeBasketStruct = new beet.BeetArgsStruct<
+  MakeBasketInstructionArgs & {
     instructionDiscriminator: number
   }
 >(
   [
     ['instructionDiscriminator', beet.u8],
-    ['initializeMyStateIxData', initializeMyStateIxDataBeet],
+    ['makeBasketIxData', makeBasketIxDataBeet],
   ],
-  'InitializeStateInstructionArgs'
+  'MakeBasketInstructionArgs'
 )
 /**
- * Accounts required by the _InitializeState_ instruction
+ * Accounts required by the _MakeBasket_ instruction
  *
- * @property [_writable_, **signer**] payerAcc
- * @property [_writable_] stateAcc
+ * @property [_writable_, **signer**] maker
+ * @property [_writable_] basketAcc
  * @property [] sysvarRentAcc
- * @property [] systemProgramAcc
+ * @property [] associatedTokenProgram
  * @category Instructions
- * @category InitializeState
+ * @category MakeBasket
  * @category generated
  */
-export type InitializeStateInstructionAccounts = {
-  payerAcc: web3.PublicKey
-  stateAcc: web3.PublicKey
+export type MakeBasketInstructionAccounts = {
+  maker: web3.PublicKey
+  basketAcc: web3.PublicKey
   sysvarRentAcc: web3.PublicKey
-  systemProgramAcc: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  associatedTokenProgram: web3.PublicKey
 }
 
-export const initializeStateInstructionDiscriminator = 0
+export const makeBasketInstructionDiscriminator = 6
 
 /**
- * Creates a _InitializeState_ instruction.
+ * Creates a _MakeBasket_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
  * @category Instructions
- * @category InitializeState
+ * @category MakeBasket
  * @category generated
  */
-export function createInitializeStateInstruction(
-  accounts: InitializeStateInstructionAccounts,
-  args: InitializeStateInstructionArgs,
+export function createMakeBasketInstruction(
+  accounts: MakeBasketInstructionAccounts,
+  args: MakeBasketInstructionArgs,
   programId = new web3.PublicKey('ENrRns55VechXJiq4bMbdx7idzQh7tvaEJoYeWxRNe7Y')
 ) {
-  const [data] = InitializeStateStruct.serialize({
-    instructionDiscriminator: initializeStateInstructionDiscriminator,
+  const [data] = MakeBasketStruct.serialize({
+    instructionDiscriminator: makeBasketInstructionDiscriminator,
     ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
-      pubkey: accounts.payerAcc,
+      pubkey: accounts.maker,
       isWritable: true,
       isSigner: true,
     },
     {
-      pubkey: accounts.stateAcc,
+      pubkey: accounts.basketAcc,
       isWritable: true,
       isSigner: false,
     },
@@ -92,7 +90,12 @@ export function createInitializeStateInstruction(
       isSigner: false,
     },
     {
-      pubkey: accounts.systemProgramAcc,
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.associatedTokenProgram,
       isWritable: false,
       isSigner: false,
     },
@@ -1,69 +0,0 @@
-/**
- * This code was GENERATED using the solita package.
- * Please DO NOT EDIT THIS FILE, instead rerun solita to update it or write a wrapper to add functionality.
- *
- * See: https://github.com/metaplex-foundation/solita
- */
-
-import * as beet from '@metaplex-foundation/beet'
-import * as web3 from '@solana/web3.js'
-
-/**
- * @category Instructions
- * @category UpdateState
- * @category generated
- */
-export const UpdateStateStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number
-}>([['instructionDiscriminator', beet.u8]], 'UpdateStateInstructionArgs')
-/**
- * Accounts required by the _UpdateState_ instruction
- *
- * @property [_writable_, **signer**] payerAcc
- * @property [_writable_] stateAcc
- * @category Instructions
- * @category UpdateState
- * @category generated
- */
-export type UpdateStateInstructionAccounts = {
-  payerAcc: web3.PublicKey
-  stateAcc: web3.PublicKey
-}
-
-export const updateStateInstructionDiscriminator = 1
-
-/**
- * Creates a _UpdateState_ instruction.
- *
- * @param accounts that will be accessed while the instruction is processed
- * @category Instructions
- * @category UpdateState
- * @category generated
- */
-export function createUpdateStateInstruction(
-  accounts: UpdateStateInstructionAccounts,
-  programId = new web3.PublicKey('ENrRns55VechXJiq4bMbdx7idzQh7tvaEJoYeWxRNe7Y')
-) {
-  const [data] = UpdateStateStruct.serialize({
-    instructionDiscriminator: updateStateInstructionDiscriminator,
-  })
-  const keys: web3.AccountMeta[] = [
-    {
-      pubkey: accounts.payerAcc,
-      isWritable: true,
-      isSigner: true,
-    },
-    {
-      pubkey: accounts.stateAcc,
-      isWritable: true,
-      isSigner: false,
-    },
-  ]
-
-  const ix = new web3.TransactionInstruction({
-    programId,
-    keys,
-    data,
-  })
-  return ix
-}
@@ -1,2 +1,9 @@
-export * from './InitializeState'
-export * from './UpdateState'
+export * from './InitializeConfig'
+export * from './InitializeOfferBook'
+export * from './MakeBasket'
+export * from './MakeEscrow'
+export * from './RefundBasket'
+export * from './RefundEscrow'
+export * from './TakeBasket'
+export * from './TakeEscrow'
+export * from './UpdateConfig'
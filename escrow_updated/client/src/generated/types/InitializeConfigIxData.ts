@@ -8,22 +8,19 @@
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
-export type InitializeMyStateIxData = {
-  owner: web3.PublicKey
-  data: number[] /* size: 32 */
-  bump: number
+export type InitializeConfigIxData = {
+  feeRecipient: web3.PublicKey
+  feeBps: number
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const initializeMyStateIxDataBeet =
-  new beet.BeetArgsStruct<InitializeMyStateIxData>(
-    [
-      ['owner', beetSolana.publicKey],
-      ['data', beet.uniformFixedSizeArray(beet.u8, 32)],
-      ['bump', beet.u8],
-    ],
-    'InitializeMyStateIxData'
-  )
+export const initializeConfigIxDataBeet = new beet.BeetArgsStruct<InitializeConfigIxData>(
+  [
+    ['feeRecipient', beetSolana.publicKey],
+    ['feeBps', beet.u16],
+  ],
+  'InitializeConfigIxData'
+)
@@ -15,6 +15,27 @@ export enum MyProgramError {
   InvalidInstructionData,
   PdaMismatch,
   InvalidOwner,
+  InvalidTaker,
+  InvalidTokenProgram,
+  UnsupportedMintExtension,
+  InvalidVaultOwner,
+  InvalidVaultMint,
+  FeeTooHigh,
+  InvalidFeeRecipient,
+  Unauthorized,
+  MissingSigner,
+  AccountNotWritable,
+  InvalidAccountOwner,
+  InvalidProgramAccount,
+  InvalidTokenAccountMint,
+  InvalidTokenAccountOwner,
+  MintMismatch,
+  OfferBookFull,
+  OfferNotListed,
+  InvalidLegCount,
+  DuplicateLegMint,
+  InvalidSeed,
+  SeedAddressMismatch,
 }
 
 /**
@@ -10,17 +10,16 @@ import * as beet from '@metaplex-foundation/beet'
  * @category enums
  * @category generated
  */
-export enum State {
-  Uninitialized,
-  Initialized,
-  Updated,
+export enum NativeSide {
+  None,
+  Offered,
+  Requested,
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const stateBeet = beet.fixedScalarEnum(State) as beet.FixedSizeBeet<
-  State,
-  State
->
+export const nativeSideBeet = beet.fixedScalarEnum(
+  NativeSide
+) as beet.FixedSizeBeet<NativeSide, NativeSide>
@@ -1,3 +1,10 @@
-export * from './InitializeMyStateIxData'
+export * from './InitializeConfigIxData'
+export * from './Leg'
+export * from './MakeBasketIxData'
+export * from './MakeEscrowIxData'
 export * from './MyProgramError'
-export * from './State'
+export * from './NativeSide'
+export * from './Offer'
+export * from './TakeBasketIxData'
+export * from './TakeEscrowIxData'
+export * from './UpdateConfigIxData'
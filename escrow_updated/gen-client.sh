@@ -1,3 +1,7 @@
-cd client
+#!/usr/bin/env bash
+set -e
+
+# Solita runs shank against ../program, so the IDL always comes from the crate's annotations
+cd "$(dirname "$0")/client"
 bun install
-bun run solita
\ No newline at end of file
+bun run solita
@@ -1,120 +1,53 @@
-use mollusk_svm::{program, Mollusk};
-use mollusk_svm_bencher::MolluskComputeUnitBencher;
-use solana_pinocchio_starter::{
-    instruction::{InitializeMyStateIxData, UpdateMyStateIxData},
-    state::{to_bytes, DataLen, MyState, State},
-    ID,
-};
-use solana_sdk::{
-    account::Account,
-    instruction::{AccountMeta, Instruction},
-    native_token::LAMPORTS_PER_SOL,
-    pubkey::Pubkey,
-    sysvar::Sysvar,
-};
-use solana_sdk::{pubkey, rent::Rent};
-
-pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+#[path = "../tests/common/mod.rs"]
+mod common;
 
-pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+use common::*;
+use mollusk_svm_bencher::MolluskComputeUnitBencher;
 
-pub const PAYER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+#[path = "../../../test_fixtures/budgets.rs"]
+mod budgets;
 
-pub fn get_rent_data() -> Vec<u8> {
-    let rent = Rent::default();
-    unsafe {
-        core::slice::from_raw_parts(&rent as *const Rent as *const u8, Rent::size_of()).to_vec()
-    }
-}
+// Take checks the escrow PDA against its stored bump. Built with `--features find-pda`
+// it re-derives the bump with find_program_address, benched under its own names.
+const TAKE_SOL_OFFER: &str = if cfg!(feature = "find-pda") {
+    "TakeEscrowSolOfferFindPda"
+} else {
+    "TakeEscrowSolOffer"
+};
+const TAKE_TOKEN_OFFER: &str = if cfg!(feature = "find-pda") {
+    "TakeEscrowTokenOfferFindPda"
+} else {
+    "TakeEscrowTokenOffer"
+};
 
 fn main() {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
-
-    let (system_program, system_account) = program::keyed_account_for_system_program();
-
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
-
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
-    let mystate_account = Account::new(0, 0, &system_program);
-    let min_balance = mollusk.sysvars.rent.minimum_balance(Rent::size_of());
-    let mut rent_account = Account::new(min_balance, Rent::size_of(), &RENT);
-    rent_account.data = get_rent_data();
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-        AccountMeta::new_readonly(RENT, false),
-        AccountMeta::new_readonly(system_program, false),
+    let mollusk = mollusk();
+
+    let (make_sol_ix, make_sol_accounts) = make_sol_offer(&mollusk);
+    let (make_token_ix, make_token_accounts) = make_token_offer(&mollusk);
+    let (take_sol_ix, take_sol_accounts) = take_sol_offer(&mollusk, MINT_B);
+    let (take_token_ix, take_token_accounts) = take_token_offer(&mollusk);
+
+    let benches = [
+        ("MakeEscrowSolOffer", &make_sol_ix, &make_sol_accounts[..]),
+        ("MakeEscrowTokenOffer", &make_token_ix, &make_token_accounts[..]),
+        (TAKE_SOL_OFFER, &take_sol_ix, &take_sol_accounts[..]),
+        (TAKE_TOKEN_OFFER, &take_token_ix, &take_token_accounts[..]),
     ];
 
-    // Create the instruction data
-    let ix_data = InitializeMyStateIxData {
-        owner: *PAYER.as_array(),
-        data: [1; 32],
-        bump,
-    };
-
-    // Ix discriminator = 0
-    let mut ser_ix_data = vec![0];
-
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
-
-    // Create instruction
-    let instruction0 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-
-    // Create tx_accounts vec
-    let tx_accounts0 = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-        (RENT, rent_account.clone()),
-        (system_program, system_account.clone()),
-    ];
-
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
-
-    let my_state = MyState {
-        is_initialized: 1,
-        owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
-        update_count: 0,
-        bump,
-    };
-
-    mystate_account.data = unsafe { to_bytes(&my_state).to_vec() };
-
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-    ];
-
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
-
-    // Ix discriminator = 1
-    let mut ser_ix_data = vec![1];
-
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
-
-    // Create instruction
-    let instruction1 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts1 = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-    ];
-
-    MolluskComputeUnitBencher::new(mollusk)
-        .bench(("InitializeMyState", &instruction0, tx_accounts0))
-        .bench(("UpdateMyState", &instruction1, tx_accounts1))
-        .must_pass(true)
-        .out_dir("benches/")
-        .execute();
+    let mut bencher = MolluskComputeUnitBencher::new(mollusk);
+    for bench in benches {
+        bencher = bencher.bench(bench);
+    }
+    bencher.must_pass(true).out_dir("benches/").execute();
+
+    // Fail the run when an instruction goes over its checked-in budget
+    let mollusk = common::mollusk();
+    let consumed: Vec<(&str, u64)> = benches
+        .iter()
+        .map(|(name, instruction, accounts)| {
+            (*name, mollusk.process_instruction(instruction, accounts).compute_units_consumed)
+        })
+        .collect();
+    budgets::check_budgets(&consumed);
 }
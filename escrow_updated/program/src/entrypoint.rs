@@ -32,5 +32,33 @@ fn process_instruction(
             msg!("Ix:1");
             instruction::process_take_escrow(accounts, instruction_data)
         }
+        EscrowInstruction::InitializeConfig => {
+            msg!("Ix:2");
+            instruction::process_initialize_config(accounts, instruction_data)
+        }
+        EscrowInstruction::UpdateConfig => {
+            msg!("Ix:3");
+            instruction::process_update_config(accounts, instruction_data)
+        }
+        EscrowInstruction::RefundEscrow => {
+            msg!("Ix:4");
+            instruction::process_refund_escrow(accounts, instruction_data)
+        }
+        EscrowInstruction::InitializeOfferBook => {
+            msg!("Ix:5");
+            instruction::process_initialize_offer_book(accounts, instruction_data)
+        }
+        EscrowInstruction::MakeBasket => {
+            msg!("Ix:6");
+            instruction::process_make_basket(accounts, instruction_data)
+        }
+        EscrowInstruction::TakeBasket => {
+            msg!("Ix:7");
+            instruction::process_take_basket(accounts, instruction_data)
+        }
+        EscrowInstruction::RefundBasket => {
+            msg!("Ix:8");
+            instruction::process_refund_basket(accounts, instruction_data)
+        }
     }
 }
@@ -10,6 +10,48 @@ pub enum MyProgramError {
     PdaMismatch,
     // Invalid Owner
     InvalidOwner,
+    // Taker not allowed to fill this escrow
+    InvalidTaker,
+    // Mint is not owned by SPL Token or Token-2022
+    InvalidTokenProgram,
+    // Mint carries a Token-2022 extension the escrow can't hold
+    UnsupportedMintExtension,
+    // Vault is not owned by the escrow
+    InvalidVaultOwner,
+    // Vault holds a different mint than the escrow
+    InvalidVaultMint,
+    // Fee above EscrowConfig::MAX_FEE_BPS
+    FeeTooHigh,
+    // Fee recipient doesn't match the escrow config
+    InvalidFeeRecipient,
+    // Signer is not the config admin
+    Unauthorized,
+    // Account must sign the transaction
+    MissingSigner,
+    // Account must be writable
+    AccountNotWritable,
+    // Account is owned by the wrong program
+    InvalidAccountOwner,
+    // Wrong program or sysvar account passed
+    InvalidProgramAccount,
+    // Token account holds a different mint than expected
+    InvalidTokenAccountMint,
+    // Token account belongs to a different wallet than expected
+    InvalidTokenAccountOwner,
+    // Mint doesn't match the one recorded in the escrow
+    MintMismatch,
+    // No free slot left in the offer book
+    OfferBookFull,
+    // Escrow isn't at the given offer book slot
+    OfferNotListed,
+    // Basket side has no legs or more than BasketEscrow::MAX_LEGS
+    InvalidLegCount,
+    // Basket side lists the same mint twice
+    DuplicateLegMint,
+    // Escrow seed is longer than MAX_SEED_LEN or not valid UTF-8
+    InvalidSeed,
+    // Escrow isn't at the address its maker and seed derive
+    SeedAddressMismatch,
 }
 
 impl From<MyProgramError> for ProgramError {
@@ -0,0 +1,80 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Creates an associated token account for the given wallet address and
+/// token mint, unless it already exists.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Associated token account address to be created
+///   2. `[]` Wallet address for the new associated token account
+///   3. `[]` The token mint for the new associated token account
+///   4. `[]` System program
+///   5. `[]` Token program
+pub struct CreateIdempotent<'a> {
+    /// Funding account.
+    pub funding_account: &'a AccountInfo,
+
+    /// Associated token account address.
+    pub account: &'a AccountInfo,
+
+    /// Wallet address owning the associated token account.
+    pub wallet: &'a AccountInfo,
+
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+
+    /// System program.
+    pub system_program: &'a AccountInfo,
+
+    /// Token program owning the mint.
+    pub token_program: &'a AccountInfo,
+}
+
+impl CreateIdempotent<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 6] = [
+            AccountMeta::writable_signer(self.funding_account.key()),
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly(self.wallet.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly(self.system_program.key()),
+            AccountMeta::readonly(self.token_program.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: &account_metas,
+            // create idempotent instruction has a '1' discriminator
+            data: &[1],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.funding_account,
+                self.account,
+                self.wallet,
+                self.mint,
+                self.system_program,
+                self.token_program,
+            ],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    ProgramResult,
+};
+
+use crate::instruction::system_interface::CreateAccount;
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_ix_data, DataLen},
+        EscrowConfig,
+    },
+    validation::{check_owner, check_pda},
+};
+
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Program data accounts start with the u32 state tag (3) and the u64 deploy
+// slot, then the upgrade authority as an option tag and pubkey.
+const PROGRAM_DATA_TAG: u32 = 3;
+const UPGRADE_AUTHORITY_OFFSET: usize = 12;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub struct InitializeConfigIxData {
+    pub fee_recipient: Pubkey,
+    pub fee_bps: u16,
+}
+
+impl DataLen for InitializeConfigIxData {
+    const LEN: usize = core::mem::size_of::<InitializeConfigIxData>();
+}
+
+pub fn process_initialize_config(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        admin,
+        config_acc,
+        sysvar_rent_acc,
+        _system_program,
+        program_data,
+        _rest @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !config_acc.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Only whoever can upgrade the program gets to set the fee config up
+    check_upgrade_authority(program_data, admin.key())?;
+
+    let ix_data = unsafe { load_ix_data::<InitializeConfigIxData>(data)? };
+
+    EscrowConfig::validate_fee_bps(ix_data.fee_bps)?;
+
+    // derive the canonical bump during account init
+    let (derived_config_pda, bump) =
+        pubkey::find_program_address(&[EscrowConfig::SEED.as_bytes()], &crate::ID);
+    if derived_config_pda.ne(config_acc.key()) {
+        return Err(MyProgramError::PdaMismatch.into());
+    }
+
+    let bump_binding = [bump];
+    // Signer seeds
+    let signer_seeds = [
+        Seed::from(EscrowConfig::SEED.as_bytes()),
+        Seed::from(&bump_binding),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    CreateAccount::with_rent_check(
+        admin,
+        config_acc,
+        sysvar_rent_acc,
+        EscrowConfig::LEN as u64,
+        &crate::ID,
+    )?
+    .invoke_signed(&signers)?;
+
+    EscrowConfig::initialize(config_acc, admin.key(), ix_data, bump)?;
+
+    Ok(())
+}
+
+/// Checks `authority` is the upgrade authority recorded in this program's
+/// program data account.
+fn check_upgrade_authority(program_data: &AccountInfo, authority: &Pubkey) -> ProgramResult {
+    let (program_data_address, _) =
+        pubkey::find_program_address(&[&crate::ID], &BPF_LOADER_UPGRADEABLE_ID);
+    check_pda(program_data, &program_data_address)?;
+    check_owner(program_data, &BPF_LOADER_UPGRADEABLE_ID)?;
+
+    let data = unsafe { program_data.borrow_data_unchecked() };
+    let Some(header) = data.get(..UPGRADE_AUTHORITY_OFFSET + 33) else {
+        return Err(ProgramError::InvalidAccountData);
+    };
+    if header[..4] != PROGRAM_DATA_TAG.to_le_bytes() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // An immutable program has no upgrade authority left to initialize it
+    if header[UPGRADE_AUTHORITY_OFFSET] != 1
+        || header[UPGRADE_AUTHORITY_OFFSET + 1..] != authority[..]
+    {
+        return Err(MyProgramError::Unauthorized.into());
+    }
+
+    Ok(())
+}
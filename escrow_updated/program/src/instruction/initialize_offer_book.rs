@@ -0,0 +1,66 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey, ProgramResult,
+};
+
+use crate::instruction::system_interface::CreateAccount;
+
+use crate::{
+    state::{utils::DataLen, OfferBook},
+    validation::{check_pda, check_signer, check_writable},
+};
+
+/// Creates the offer book for a mint pair. Pass the system program in place
+/// of a mint for a SOL side.
+pub fn process_initialize_offer_book(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [
+        payer,
+        offer_book_acc,
+        mint_a,
+        mint_b,
+        sysvar_rent_acc,
+        _system_program,
+        _rest @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_signer(payer)?;
+    check_writable(offer_book_acc)?;
+
+    if !offer_book_acc.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // derive the canonical bump during account init
+    let (offer_book_pda, bump) = pubkey::find_program_address(
+        &[OfferBook::SEED.as_bytes(), mint_a.key(), mint_b.key()],
+        &crate::ID,
+    );
+    check_pda(offer_book_acc, &offer_book_pda)?;
+
+    let bump_binding = [bump];
+    // Signer seeds
+    let signer_seeds = [
+        Seed::from(OfferBook::SEED.as_bytes()),
+        Seed::from(mint_a.key()),
+        Seed::from(mint_b.key()),
+        Seed::from(&bump_binding),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    CreateAccount::with_rent_check(
+        payer,
+        offer_book_acc,
+        sysvar_rent_acc,
+        OfferBook::LEN as u64,
+        &crate::ID,
+    )?
+    .invoke_signed(&signers)?;
+
+    OfferBook::initialize(offer_book_acc, mint_a.key(), mint_b.key(), bump)?;
+
+    Ok(())
+}
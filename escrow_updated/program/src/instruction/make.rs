@@ -2,66 +2,87 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::Pubkey,
+    pubkey::{self, Pubkey},
+    sysvars::rent::RENT_ID,
     ProgramResult,
 };
 
-// use pinocchio_system::instructions::CreateAccount;
-use crate::instruction::create_account::CreateAccount;
-use crate::instruction::create_account_with_seed::CreateAccountWithSeed;
+use crate::instruction::system_interface::{CreateAccount, CreateAccountWithSeed, Transfer};
+use crate::instruction::create_associated_token_account::{
+    CreateIdempotent, ASSOCIATED_TOKEN_PROGRAM_ID,
+};
 
-use pinocchio_token::{ instructions::TransferChecked, state::{ Mint, TokenAccount } };
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, validate_mint_extensions, TransferChecked,
+};
 
 use crate::{
     error::MyProgramError,
     state::{
-        utils::{load_ix_data, DataLen},
-        EscrowState,
+        utils::{create_with_seed, load_ix_data, DataLen},
+        EscrowState, NativeSide, OfferBook,
     },
+    validation::{check_mint, check_pda, check_program, check_signer, check_token_account, check_writable},
 };
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
 pub struct MakeEscrowIxData {
     pub maker: Pubkey,
+    pub designated_taker: Pubkey,
+    /// Creates the escrow at `create_with_seed(maker, seed, program_id)` instead of its PDA.
+    pub seed: [u8; 32],
+    pub amount: u64,
     pub receive_amount: u64,
-    pub bump: u8,
+    pub native_side: u8,
+    /// Length of `seed`, 0 to create the escrow at its PDA.
+    pub seed_len: u8,
+    pub _padding: [u8; 6],
 }
 
 impl DataLen for MakeEscrowIxData {
     const LEN: usize = core::mem::size_of::<MakeEscrowIxData>(); // 32 bytes for Pubkey + 32 bytes for data
 }
 
+impl MakeEscrowIxData {
+    pub fn seed(&self) -> Result<&str, ProgramError> {
+        let seed = self
+            .seed
+            .get(..self.seed_len as usize)
+            .ok_or(MyProgramError::InvalidSeed)?;
+        core::str::from_utf8(seed).map_err(|_| MyProgramError::InvalidSeed.into())
+    }
+}
+
 pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let [
         maker,
         mint_a,
+        mint_b,
         escrow_acc,
         vault,
         maker_ata_a,
         sysvar_rent_acc,
-        _system_program,
-        _rest @..
+        system_program,
+        token_program_a,
+        associated_token_program,
+        vault_authority,
+        rest @..
     ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    if !maker.is_signer() {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    check_signer(maker)?;
+    check_writable(maker)?;
+    check_writable(escrow_acc)?;
+    check_writable(vault)?;
+    check_program(sysvar_rent_acc, &RENT_ID)?;
+    check_program(system_program, &pinocchio_system::ID)?;
 
     // if !escrow_acc.data_is_empty() {
     //     return Err(ProgramError::AccountAlreadyInitialized);
     // }
 
-    let vault_acc = TokenAccount::from_account_info(vault)?;
-    assert_eq!(vault_acc.owner(), escrow_acc.key());
-
-    let maker_ata_a_acc = TokenAccount::from_account_info(maker_ata_a)?;
-    assert_eq!(maker_ata_a_acc.owner(), maker.key());
-
-    let mint_a_acc = Mint::from_account_info(mint_a)?;
-
     // let rent = Rent::from_account_info(sysvar_rent_acc)?;
 
     let ix_data = unsafe { load_ix_data::<MakeEscrowIxData>(data)? };
@@ -70,37 +91,75 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(MyProgramError::InvalidOwner.into());
     }
 
-    let pda_bump_bytes = [ix_data.bump];
+    let native_side = NativeSide::try_from(ix_data.native_side)?;
 
-    EscrowState::validate_pda(ix_data.bump, escrow_acc.key(), &ix_data.maker)?;
+    // Record mint B up front so take can check it, `mint_b` is unused when SOL is requested
+    let mint_b_key = if native_side == NativeSide::Requested {
+        Pubkey::default()
+    } else {
+        let mint_b_program = token_program_of(mint_b)?;
+        check_mint(mint_b, mint_b_program)?;
+        validate_mint_extensions(mint_b)?;
+        *mint_b.key()
+    };
 
-    // Signer seeds
-    let signer_seeds = [
-        Seed::from(EscrowState::SEED.as_bytes()),
-        Seed::from(&ix_data.maker),
-        Seed::from(&pda_bump_bytes[..]),
-    ];
-    let signers = [Signer::from(&signer_seeds[..])];
+    // A seeded escrow is created by the maker's own signature and can't sign for its vault,
+    // so a PDA of the escrow owns the vault instead. A PDA escrow is its own vault authority.
+    let bump = if ix_data.seed_len > 0 {
+        let seed = ix_data.seed()?;
 
-    let create_account_ctx = CreateAccount::with_rent_check(
-        maker,
-        escrow_acc,
-        sysvar_rent_acc,
-        EscrowState::LEN as u64,
-        &crate::ID,        
-    )?;
-    create_account_ctx.invoke_signed(&signers)?;
-
-    // let create_account_with_seed_ctx = CreateAccountWithSeed::with_rent_check(
-    //     maker,
-    //     escrow_acc,
-    //     None,
-    //     "escrow",
-    //     sysvar_rent_acc,
-    //     EscrowState::LEN as u64,
-    //     &crate::ID,        
-    // )?;
-    // create_account_with_seed_ctx.invoke_signed(&signers)?;
+        if create_with_seed(maker.key(), seed.as_bytes(), &crate::ID)?.ne(escrow_acc.key()) {
+            return Err(MyProgramError::SeedAddressMismatch.into());
+        }
+
+        let (vault_authority_pda, bump) = pubkey::find_program_address(
+            &[EscrowState::VAULT_AUTHORITY_SEED.as_bytes(), escrow_acc.key()],
+            &crate::ID,
+        );
+        check_pda(vault_authority, &vault_authority_pda)?;
+
+        CreateAccountWithSeed::with_rent_check(
+            maker,
+            escrow_acc,
+            None,
+            seed,
+            sysvar_rent_acc,
+            EscrowState::LEN as u64,
+            &crate::ID,
+        )?
+        .invoke()?;
+
+        bump
+    } else {
+        // derive the canonical bump during account init, later instructions reuse the stored one
+        let (escrow_pda, bump) = pubkey::find_program_address(
+            &[EscrowState::SEED.as_bytes(), &ix_data.maker],
+            &crate::ID,
+        );
+        check_pda(escrow_acc, &escrow_pda)?;
+        check_pda(vault_authority, &escrow_pda)?;
+
+        let pda_bump_bytes = [bump];
+
+        // Signer seeds
+        let signer_seeds = [
+            Seed::from(EscrowState::SEED.as_bytes()),
+            Seed::from(&ix_data.maker),
+            Seed::from(&pda_bump_bytes[..]),
+        ];
+        let signers = [Signer::from(&signer_seeds[..])];
+
+        let create_account_ctx = CreateAccount::with_rent_check(
+            maker,
+            escrow_acc,
+            sysvar_rent_acc,
+            EscrowState::LEN as u64,
+            &crate::ID,
+        )?;
+        create_account_ctx.invoke_signed(&signers)?;
+
+        bump
+    };
 
     // // Create the governance config account
     // CreateAccount {
@@ -113,18 +172,95 @@ pub fn process_make_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
     // }
     // .invoke_signed(&signers)?;
 
-    
+    if native_side == NativeSide::Offered {
+        // SOL is held by a system-owned PDA, so `mint_a`, `maker_ata_a` and the token programs are unused
+        let (sol_vault, sol_vault_bump) = pubkey::find_program_address(
+            &[EscrowState::SOL_VAULT_SEED.as_bytes(), escrow_acc.key()],
+            &crate::ID,
+        );
+        check_pda(vault, &sol_vault)?;
 
-    EscrowState::make(escrow_acc, ix_data)?;
+        Transfer {
+            from: maker,
+            to: vault,
+            lamports: ix_data.amount,
+        }
+        .invoke()?;
+
+        EscrowState::make(
+            escrow_acc,
+            ix_data,
+            &Pubkey::default(),
+            &mint_b_key,
+            ix_data.amount,
+            bump,
+            sol_vault_bump,
+        )?;
+
+        // Listing is optional, the pair's offer book follows the required accounts
+        if let Some(offer_book) = rest.first() {
+            OfferBook::list(offer_book, escrow_acc)?;
+        }
+
+        return Ok(());
+    }
+
+    let mint_a_program = token_program_of(mint_a)?;
+    if token_program_a.key().ne(mint_a_program) {
+        return Err(MyProgramError::InvalidTokenProgram.into());
+    }
+
+    check_program(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+    validate_mint_extensions(mint_a)?;
+
+    check_writable(maker_ata_a)?;
+    check_token_account(maker_ata_a, mint_a_program, mint_a.key(), maker.key())?;
+
+    let mint_a_acc = check_mint(mint_a, mint_a_program)?;
+
+    // The vault is the vault authority's associated token account for mint A
+    CreateIdempotent {
+        funding_account: maker,
+        account: vault,
+        wallet: vault_authority,
+        mint: mint_a,
+        system_program,
+        token_program: token_program_a,
+    }
+    .invoke()?;
+
+    let vault_acc = load_token_account(vault, mint_a_program)?;
+    if vault_acc.owner().ne(vault_authority.key()) {
+        return Err(MyProgramError::InvalidVaultOwner.into());
+    }
+    if vault_acc.mint().ne(mint_a.key()) {
+        return Err(MyProgramError::InvalidVaultMint.into());
+    }
+
+    let vault_balance = vault_acc.amount();
 
     (TransferChecked {
         from: maker_ata_a,
         to: vault,
         authority: maker,
         mint: mint_a,
-        amount: ix_data.receive_amount,
+        amount: ix_data.amount,
         decimals: mint_a_acc.decimals(),
+        token_program: mint_a_program,
     }).invoke()?;
 
+    // Transfer-fee mints deliver less than `amount`, so escrow what actually landed in the vault
+    let deposited = load_token_account(vault, mint_a_program)?
+        .amount()
+        .checked_sub(vault_balance)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    EscrowState::make(escrow_acc, ix_data, mint_a.key(), &mint_b_key, deposited, bump, 0)?;
+
+    if let Some(offer_book) = rest.first() {
+        OfferBook::list(offer_book, escrow_acc)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,180 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    sysvars::rent::RENT_ID,
+    ProgramResult,
+};
+
+use crate::instruction::system_interface::CreateAccount;
+use crate::instruction::create_associated_token_account::{
+    CreateIdempotent, ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, validate_mint_extensions, TransferChecked,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_ix_data, DataLen},
+        BasketEscrow, Leg,
+    },
+    validation::{check_mint, check_pda, check_program, check_signer, check_token_account, check_writable},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub struct MakeBasketIxData {
+    pub maker: Pubkey,
+    // shank only reads literal array lengths, keep in step with BasketEscrow::MAX_LEGS
+    /// Amounts of the offered legs, in the order their accounts are passed.
+    pub offered_amounts: [u64; 4],
+    pub requested: [Leg; 4],
+    pub offered_count: u8,
+    pub requested_count: u8,
+    pub _padding: [u8; 6],
+}
+
+impl DataLen for MakeBasketIxData {
+    const LEN: usize = core::mem::size_of::<MakeBasketIxData>();
+}
+
+/// Accounts passed per offered leg after the fixed accounts: mint, maker token
+/// account, vault (the basket's ATA for the mint) and the mint's token program.
+pub const MAKE_BASKET_LEG_ACCOUNTS: usize = 4;
+
+pub fn process_make_basket(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        maker,
+        basket_acc,
+        sysvar_rent_acc,
+        system_program,
+        associated_token_program,
+        leg_accounts @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_signer(maker)?;
+    check_writable(maker)?;
+    check_writable(basket_acc)?;
+    check_program(sysvar_rent_acc, &RENT_ID)?;
+    check_program(system_program, &pinocchio_system::ID)?;
+    check_program(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+    let ix_data = unsafe { load_ix_data::<MakeBasketIxData>(data)? };
+
+    if ix_data.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    let offered_count = BasketEscrow::validate_leg_count(ix_data.offered_count)?;
+    let requested_count = BasketEscrow::validate_leg_count(ix_data.requested_count)?;
+
+    BasketEscrow::validate_unique_mints(&ix_data.requested[..requested_count])?;
+
+    if leg_accounts.len() < offered_count * MAKE_BASKET_LEG_ACCOUNTS {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // derive the canonical bump during account init
+    let (basket_pda, bump) = pubkey::find_program_address(
+        &[BasketEscrow::SEED.as_bytes(), &ix_data.maker],
+        &crate::ID,
+    );
+    check_pda(basket_acc, &basket_pda)?;
+
+    let pda_bump_bytes = [bump];
+
+    // Signer seeds
+    let signer_seeds = [
+        Seed::from(BasketEscrow::SEED.as_bytes()),
+        Seed::from(&ix_data.maker),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    CreateAccount::with_rent_check(
+        maker,
+        basket_acc,
+        sysvar_rent_acc,
+        BasketEscrow::LEN as u64,
+        &crate::ID,
+    )?
+    .invoke_signed(&signers)?;
+
+    let mut offered = [Leg::default(); BasketEscrow::MAX_LEGS];
+
+    for (i, leg) in leg_accounts
+        .chunks_exact(MAKE_BASKET_LEG_ACCOUNTS)
+        .take(offered_count)
+        .enumerate()
+    {
+        let [mint, maker_ata, vault, token_program] = leg else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let mint_program = token_program_of(mint)?;
+        if token_program.key().ne(mint_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        validate_mint_extensions(mint)?;
+
+        check_writable(maker_ata)?;
+        check_token_account(maker_ata, mint_program, mint.key(), maker.key())?;
+
+        check_writable(vault)?;
+
+        let mint_acc = check_mint(mint, mint_program)?;
+
+        // Each leg's vault is the basket PDA's associated token account for its mint
+        CreateIdempotent {
+            funding_account: maker,
+            account: vault,
+            wallet: basket_acc,
+            mint,
+            system_program,
+            token_program,
+        }
+        .invoke()?;
+
+        let vault_acc = load_token_account(vault, mint_program)?;
+        if vault_acc.owner().ne(basket_acc.key()) {
+            return Err(MyProgramError::InvalidVaultOwner.into());
+        }
+        if vault_acc.mint().ne(mint.key()) {
+            return Err(MyProgramError::InvalidVaultMint.into());
+        }
+
+        let vault_balance = vault_acc.amount();
+
+        TransferChecked {
+            from: maker_ata,
+            to: vault,
+            authority: maker,
+            mint,
+            amount: ix_data.offered_amounts[i],
+            decimals: mint_acc.decimals(),
+            token_program: mint_program,
+        }.invoke()?;
+
+        // Escrow what actually landed in the vault, as in MakeEscrow
+        offered[i] = Leg {
+            mint: *mint.key(),
+            amount: load_token_account(vault, mint_program)?
+                .amount()
+                .checked_sub(vault_balance)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        };
+    }
+
+    BasketEscrow::validate_unique_mints(&offered[..offered_count])?;
+
+    BasketEscrow::make(basket_acc, ix_data, &offered, bump)?;
+
+    Ok(())
+}
@@ -2,18 +2,41 @@ use pinocchio::program_error::ProgramError;
 
 pub mod make;
 pub mod take;
-pub mod create_account;
-pub mod create_account_with_seed;
+pub mod initialize_config;
+pub mod update_config;
+pub mod refund;
+pub mod initialize_offer_book;
+pub mod make_basket;
+pub mod take_basket;
+pub mod refund_basket;
+pub mod create_associated_token_account;
+pub mod token_interface;
+pub mod system_interface;
 
 pub use make::*;
 pub use take::*;
-pub use create_account::*;
-pub use create_account_with_seed::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use refund::*;
+pub use initialize_offer_book::*;
+pub use make_basket::*;
+pub use take_basket::*;
+pub use refund_basket::*;
+pub use create_associated_token_account::*;
+pub use token_interface::*;
+pub use system_interface::*;
 
 #[repr(u8)]
 pub enum EscrowInstruction {
     MakeEscrow,
     TakeEscrow,
+    InitializeConfig,
+    UpdateConfig,
+    RefundEscrow,
+    InitializeOfferBook,
+    MakeBasket,
+    TakeBasket,
+    RefundBasket,
 }
 
 impl TryFrom<&u8> for EscrowInstruction {
@@ -23,36 +46,112 @@ impl TryFrom<&u8> for EscrowInstruction {
         match *value {
             0 => Ok(EscrowInstruction::MakeEscrow),
             1 => Ok(EscrowInstruction::TakeEscrow),
+            2 => Ok(EscrowInstruction::InitializeConfig),
+            3 => Ok(EscrowInstruction::UpdateConfig),
+            4 => Ok(EscrowInstruction::RefundEscrow),
+            5 => Ok(EscrowInstruction::InitializeOfferBook),
+            6 => Ok(EscrowInstruction::MakeBasket),
+            7 => Ok(EscrowInstruction::TakeBasket),
+            8 => Ok(EscrowInstruction::RefundBasket),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
 }
 
 mod idl_gen {
-    use super::{MakeEscrowIxData, TakeEscrowIxData};
+    use super::{
+        InitializeConfigIxData, MakeBasketIxData, MakeEscrowIxData, TakeBasketIxData,
+        TakeEscrowIxData, UpdateConfigIxData,
+    };
 
+    // Account order mirrors the destructuring in each processor
     #[derive(shank::ShankInstruction)]
     enum _EscrowInstruction {
         #[account(0, writable, signer, name = "maker", desc = "The user creating the escrow")]
         #[account(1, name = "mint_a", desc = "The mint of the token the maker is offering")]
-        #[account(2, writable, name = "escrow_acc", desc = "The escrow state account (PDA)")]
-        #[account(3, writable, name = "vault", desc = "The vault token account (PDA)")]
-        #[account(4, writable, name = "maker_ata_a", desc = "The maker's token account for mint A")]
-        #[account(5, name = "system_program", desc = "System program")]
-        #[account(6, name = "token_program", desc = "Token program")]
-        #[account(7, name = "rent", desc = "Rent sysvar")]
+        #[account(2, name = "mint_b", desc = "The mint of the token the maker wants in return")]
+        #[account(3, writable, name = "escrow_acc", desc = "The escrow state account, at its PDA or the address its seed derives")]
+        #[account(4, writable, name = "vault", desc = "The vault token account, or the SOL vault PDA when SOL is offered")]
+        #[account(5, writable, name = "maker_ata_a", desc = "The maker's token account for mint A")]
+        #[account(6, name = "sysvar_rent_acc", desc = "Rent sysvar")]
+        #[account(7, name = "system_program", desc = "System program")]
+        #[account(8, name = "token_program_a", desc = "Token program of mint A")]
+        #[account(9, name = "associated_token_program", desc = "Associated token account program")]
+        #[account(10, name = "vault_authority", desc = "Owner of the vault, the escrow itself or its vault authority PDA when seeded")]
+        #[account(11, optional, writable, name = "offer_book", desc = "Offer book of the mint pair to list the escrow in")]
         MakeEscrow(MakeEscrowIxData),
 
         #[account(0, writable, signer, name = "taker", desc = "The user fulfilling the escrow")]
         #[account(1, writable, name = "maker", desc = "The original maker of the escrow")]
         #[account(2, writable, name = "escrow_acc", desc = "The escrow state account to be closed")]
         #[account(3, writable, name = "vault", desc = "The vault account to be closed")]
-        #[account(4, name = "mint_a", desc = "The mint of the token the maker offered")]
-        #[account(5, name = "mint_b", desc = "The mint of the token the taker is offering")]
+        #[account(4, writable, name = "mint_a", desc = "The mint of the token the maker offered, harvests the vault's withheld transfer fees")]
+        #[account(5, name = "mint_b", desc = "The mint of the token the taker is paying with")]
         #[account(6, writable, name = "taker_ata_a", desc = "The taker's token account for mint A")]
         #[account(7, writable, name = "taker_ata_b", desc = "The taker's token account for mint B")]
         #[account(8, writable, name = "maker_ata_b", desc = "The maker's token account for mint B")]
         #[account(9, name = "system_program", desc = "System program")]
+        #[account(10, name = "token_program_a", desc = "Token program of mint A")]
+        #[account(11, name = "token_program_b", desc = "Token program of mint B")]
+        #[account(12, name = "config_acc", desc = "The escrow config account (PDA)")]
+        #[account(13, writable, name = "fee_recipient", desc = "The fee recipient's token account for mint B, or wallet when SOL is requested")]
+        #[account(14, name = "vault_authority", desc = "Owner of the vault, the escrow itself or its vault authority PDA when seeded")]
+        #[account(15, writable, name = "maker_ata_a", desc = "The maker's token account for mint A, receives tokens sent to the vault on top of the deposit")]
+        #[account(16, optional, writable, name = "offer_book", desc = "Offer book the escrow is listed in, required when listed")]
         TakeEscrow(TakeEscrowIxData),
+
+        #[account(0, writable, signer, name = "admin", desc = "The config admin, the program's upgrade authority, pays for the account")]
+        #[account(1, writable, name = "config_acc", desc = "The escrow config account (PDA)")]
+        #[account(2, name = "sysvar_rent_acc", desc = "Rent sysvar")]
+        #[account(3, name = "system_program", desc = "System program")]
+        #[account(4, name = "program_data", desc = "The escrow program's program data account, holding its upgrade authority")]
+        InitializeConfig(InitializeConfigIxData),
+
+        #[account(0, signer, name = "admin", desc = "The config admin")]
+        #[account(1, writable, name = "config_acc", desc = "The escrow config account (PDA)")]
+        UpdateConfig(UpdateConfigIxData),
+
+        #[account(0, writable, signer, name = "maker", desc = "The maker of the escrow")]
+        #[account(1, writable, name = "escrow_acc", desc = "The escrow state account to be closed")]
+        #[account(2, writable, name = "vault", desc = "The vault account to be closed")]
+        #[account(3, writable, name = "mint_a", desc = "The mint of the token the maker offered, harvests the vault's withheld transfer fees")]
+        #[account(4, writable, name = "maker_ata_a", desc = "The maker's token account for mint A")]
+        #[account(5, name = "system_program", desc = "System program")]
+        #[account(6, name = "token_program_a", desc = "Token program of mint A")]
+        #[account(7, name = "vault_authority", desc = "Owner of the vault, the escrow itself or its vault authority PDA when seeded")]
+        #[account(8, optional, writable, name = "offer_book", desc = "Offer book the escrow is listed in, required when listed")]
+        RefundEscrow,
+
+        #[account(0, writable, signer, name = "payer", desc = "Pays for the offer book")]
+        #[account(1, writable, name = "offer_book_acc", desc = "The offer book account (PDA)")]
+        #[account(2, name = "mint_a", desc = "Offered mint, the system program for SOL")]
+        #[account(3, name = "mint_b", desc = "Requested mint, the system program for SOL")]
+        #[account(4, name = "sysvar_rent_acc", desc = "Rent sysvar")]
+        #[account(5, name = "system_program", desc = "System program")]
+        InitializeOfferBook,
+
+        // Followed by mint, maker token account, vault and token program per offered leg
+        #[account(0, writable, signer, name = "maker", desc = "The user creating the basket")]
+        #[account(1, writable, name = "basket_acc", desc = "The basket escrow account (PDA)")]
+        #[account(2, name = "sysvar_rent_acc", desc = "Rent sysvar")]
+        #[account(3, name = "system_program", desc = "System program")]
+        #[account(4, name = "associated_token_program", desc = "Associated token account program")]
+        MakeBasket(MakeBasketIxData),
+
+        // Followed by mint, taker token account, maker token account and token program per
+        // requested leg, then mint, vault, taker token account and token program per offered leg,
+        // offered mints charging transfer fees are writable
+        #[account(0, writable, signer, name = "taker", desc = "The user fulfilling the basket")]
+        #[account(1, writable, name = "maker", desc = "The original maker of the basket")]
+        #[account(2, writable, name = "basket_acc", desc = "The basket escrow account to be closed")]
+        #[account(3, name = "system_program", desc = "System program")]
+        TakeBasket(TakeBasketIxData),
+
+        // Followed by mint, vault, maker token account and token program per offered leg,
+        // mints charging transfer fees are writable
+        #[account(0, writable, signer, name = "maker", desc = "The maker of the basket")]
+        #[account(1, writable, name = "basket_acc", desc = "The basket escrow account to be closed")]
+        #[account(2, name = "system_program", desc = "System program")]
+        RefundBasket,
     }
 }
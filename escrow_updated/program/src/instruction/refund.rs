@@ -0,0 +1,103 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::instruction::take::release_sol_vault;
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, CloseVault,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{EscrowState, NativeSide, OfferBook},
+    validation::{check_mint, check_owner, check_program, check_signer, check_token_account, check_writable},
+};
+
+pub fn process_refund_escrow(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [
+        maker,
+        escrow_acc,
+        vault,
+        mint_a,
+        maker_ata_a,
+        system_program,
+        token_program_a,
+        vault_authority,
+        rest @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_signer(maker)?;
+    check_writable(maker)?;
+    check_writable(escrow_acc)?;
+    check_writable(vault)?;
+    check_program(system_program, &pinocchio_system::ID)?;
+
+    check_owner(escrow_acc, &crate::ID)?;
+
+    let escrow_state = EscrowState::from_account_info(escrow_acc)?;
+
+    if escrow_state.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    escrow_state.validate_address(escrow_acc.key(), maker.key())?;
+
+    escrow_state.check_vault_authority(vault_authority.key(), escrow_acc.key())?;
+
+    // Return the deposit, `mint_a`, `maker_ata_a` and `token_program_a` are unused for a SOL vault
+    if escrow_state.native_side == NativeSide::Offered {
+        release_sol_vault(escrow_acc, vault, maker, &escrow_state)?;
+    } else {
+        if escrow_state.mint_a.ne(mint_a.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_a_program = token_program_of(mint_a)?;
+        if token_program_a.key().ne(mint_a_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        let vault_acc = load_token_account(vault, mint_a_program)?;
+        if vault_acc.owner().ne(vault_authority.key()) {
+            return Err(MyProgramError::InvalidVaultOwner.into());
+        }
+        if vault_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidVaultMint.into());
+        }
+
+        check_writable(maker_ata_a)?;
+        check_token_account(maker_ata_a, mint_a_program, mint_a.key(), maker.key())?;
+
+        let mint_a_acc = check_mint(mint_a, mint_a_program)?;
+
+        // The maker gets back everything in the vault, not just the deposit
+        escrow_state.with_vault_signer(escrow_acc.key(), |signers| {
+            CloseVault {
+                vault,
+                mint: mint_a,
+                to: maker_ata_a,
+                excess_to: maker_ata_a,
+                authority: vault_authority,
+                destination: maker,
+                amount: escrow_state.amount,
+                decimals: mint_a_acc.decimals(),
+                token_program: mint_a_program,
+            }.invoke_signed(signers)
+        })?;
+    }
+
+    if escrow_state.offer_slot != OfferBook::NIL {
+        let Some(offer_book) = rest.first() else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        OfferBook::delist(offer_book, escrow_acc, &escrow_state)?;
+    }
+
+    EscrowState::close(escrow_acc, maker)?;
+
+    Ok(())
+}
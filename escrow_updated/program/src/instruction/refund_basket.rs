@@ -0,0 +1,112 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, CloseVault,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{close_account, try_from_account_info, BasketEscrow},
+    validation::{check_mint, check_owner, check_program, check_signer, check_token_account, check_writable},
+};
+
+/// Accounts passed per offered leg after the fixed accounts: mint, vault, maker
+/// token account and the mint's token program. The mint has to be writable when
+/// it charges transfer fees.
+pub const REFUND_BASKET_LEG_ACCOUNTS: usize = 4;
+
+pub fn process_refund_basket(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [
+        maker,
+        basket_acc,
+        system_program,
+        leg_accounts @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_signer(maker)?;
+    check_writable(maker)?;
+    check_writable(basket_acc)?;
+    check_program(system_program, &pinocchio_system::ID)?;
+
+    check_owner(basket_acc, &crate::ID)?;
+
+    let basket = unsafe { *try_from_account_info::<BasketEscrow>(basket_acc)? };
+
+    if basket.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    BasketEscrow::validate_pda(basket.bump, basket_acc.key(), maker.key())?;
+
+    let offered_count = basket.offered_count as usize;
+
+    if leg_accounts.len() < offered_count * REFUND_BASKET_LEG_ACCOUNTS {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let pda_bump_bytes = [basket.bump];
+
+    let signer_seeds = [
+        Seed::from(BasketEscrow::SEED.as_bytes()),
+        Seed::from(maker.key()),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    // Hand every offered leg back to the maker and close its vault
+    for (leg, accounts) in basket.offered[..offered_count]
+        .iter()
+        .zip(leg_accounts.chunks_exact(REFUND_BASKET_LEG_ACCOUNTS))
+    {
+        let [mint, vault, maker_ata, token_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if leg.mint.ne(mint.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_program = token_program_of(mint)?;
+        if token_program.key().ne(mint_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        check_writable(vault)?;
+        let vault_acc = load_token_account(vault, mint_program)?;
+        if vault_acc.owner().ne(basket_acc.key()) {
+            return Err(MyProgramError::InvalidVaultOwner.into());
+        }
+        if vault_acc.mint().ne(mint.key()) {
+            return Err(MyProgramError::InvalidVaultMint.into());
+        }
+
+        check_writable(maker_ata)?;
+        check_token_account(maker_ata, mint_program, mint.key(), maker.key())?;
+
+        let mint_acc = check_mint(mint, mint_program)?;
+
+        // The maker gets the vault's whole balance, tokens sent to it included
+        CloseVault {
+            vault,
+            mint,
+            to: maker_ata,
+            excess_to: maker_ata,
+            authority: basket_acc,
+            destination: maker,
+            amount: leg.amount,
+            decimals: mint_acc.decimals(),
+            token_program: mint_program,
+        }.invoke_signed(&signers)?;
+    }
+
+    close_account(basket_acc, maker)?;
+
+    Ok(())
+}
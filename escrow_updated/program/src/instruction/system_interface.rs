@@ -0,0 +1,408 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEED_LEN},
+    sysvars::rent::Rent,
+    ProgramResult,
+};
+
+use crate::error::MyProgramError;
+
+// System Program instruction discriminators, bincode encodes them as a u32.
+const CREATE_ACCOUNT: u32 = 0;
+const ASSIGN: u32 = 1;
+const TRANSFER: u32 = 2;
+const CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+const ALLOCATE: u32 = 8;
+
+/// Longest `CreateAccountWithSeed` instruction data, with a `MAX_SEED_LEN` seed.
+pub const CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN: usize = 92 + MAX_SEED_LEN;
+
+/// Create a new account, or take over one already holding lamports.
+///
+/// The System Program refuses to create an account with a balance, so anyone could
+/// block a PDA by sending lamports to it. A pre-funded account is instead topped up
+/// to `lamports`, allocated and assigned to `owner`.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE, SIGNER]` New account
+pub struct CreateAccount<'a> {
+    /// Funding account.
+    pub from: &'a AccountInfo,
+
+    /// New account.
+    pub to: &'a AccountInfo,
+
+    /// Balance the new account ends up with.
+    pub lamports: u64,
+
+    /// Number of bytes of memory to allocate.
+    pub space: u64,
+
+    /// Address of program that will own the new account.
+    pub owner: &'a Pubkey,
+}
+
+impl<'a> CreateAccount<'a> {
+    /// Sizes the account's balance for rent exemption.
+    pub fn with_rent_check(
+        from: &'a AccountInfo,
+        to: &'a AccountInfo,
+        rent_sysvar: &'a AccountInfo,
+        space: u64,
+        owner: &'a Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let rent = Rent::from_account_info(rent_sysvar)?;
+        let lamports = rent.minimum_balance(space as usize);
+
+        if from.lamports() < lamports.saturating_sub(to.lamports()) {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        if !to.data_is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            from,
+            to,
+            lamports,
+            space,
+            owner,
+        })
+    }
+
+    /// - `[0..4  ]`: instruction discriminator
+    /// - `[4..12 ]`: lamports
+    /// - `[12..20]`: account space
+    /// - `[20..52]`: owner pubkey
+    pub fn instruction_data(lamports: u64, space: u64, owner: &Pubkey) -> [u8; 52] {
+        let mut instruction_data = [0; 52];
+        instruction_data[0..4].copy_from_slice(&CREATE_ACCOUNT.to_le_bytes());
+        instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+        instruction_data[12..20].copy_from_slice(&space.to_le_bytes());
+        instruction_data[20..52].copy_from_slice(owner);
+        instruction_data
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let balance = self.to.lamports();
+
+        if balance > 0 {
+            if balance < self.lamports {
+                Transfer {
+                    from: self.from,
+                    to: self.to,
+                    lamports: self.lamports - balance,
+                }
+                .invoke_signed(signers)?;
+            }
+
+            Allocate {
+                account: self.to,
+                space: self.space,
+            }
+            .invoke_signed(signers)?;
+
+            return Assign {
+                account: self.to,
+                owner: self.owner,
+            }
+            .invoke_signed(signers);
+        }
+
+        // account metadata, the new account signs so it can't be created behind its back
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable_signer(self.from.key()),
+            AccountMeta::writable_signer(self.to.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_system::ID,
+            accounts: &account_metas,
+            data: &Self::instruction_data(self.lamports, self.space, self.owner),
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to], signers)
+    }
+}
+
+/// Create a new account at an address derived from a base pubkey and a seed.
+///
+/// Unlike `CreateAccount` there is no pre-funded path, the base would have to sign
+/// `AllocateWithSeed`/`AssignWithSeed` instead; pick another seed.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Created account
+///   2. `[SIGNER]` (optional) Base account; the account matching the base Pubkey below must be
+///      provided as a signer, but may be the same as the funding account
+pub struct CreateAccountWithSeed<'a, 'b, 'c> {
+    /// Funding account.
+    pub from: &'a AccountInfo,
+
+    /// New account.
+    pub to: &'a AccountInfo,
+
+    /// Base account.
+    ///
+    /// The account matching the base Pubkey below must be provided as
+    /// a signer, but may be the same as the funding account and provided
+    /// as account 0.
+    pub base: Option<&'a AccountInfo>,
+
+    /// String of ASCII chars, no longer than `Pubkey::MAX_SEED_LEN`.
+    pub seed: &'b str,
+
+    /// Number of lamports to transfer to the new account.
+    pub lamports: u64,
+
+    /// Number of bytes of memory to allocate.
+    pub space: u64,
+
+    /// Address of program that will own the new account.
+    pub owner: &'c Pubkey,
+}
+
+impl<'a, 'b, 'c> CreateAccountWithSeed<'a, 'b, 'c> {
+    /// Sizes the account's balance for rent exemption.
+    pub fn with_rent_check(
+        from: &'a AccountInfo,
+        to: &'a AccountInfo,
+        base: Option<&'a AccountInfo>,
+        seed: &'b str,
+        rent_sysvar: &'a AccountInfo,
+        space: u64,
+        owner: &'c Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let rent = Rent::from_account_info(rent_sysvar)?;
+        let lamports = rent.minimum_balance(space as usize);
+
+        if seed.len() > MAX_SEED_LEN {
+            return Err(MyProgramError::InvalidSeed.into());
+        }
+
+        if from.lamports() < lamports {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        if to.lamports() > 0 || !to.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        Ok(Self {
+            from,
+            to,
+            base,
+            seed,
+            lamports,
+            space,
+            owner,
+        })
+    }
+
+    /// Writes the instruction data into `buffer` and returns its used part.
+    ///
+    /// - `[0..4  ]`: instruction discriminator
+    /// - `[4..36 ]`: base pubkey
+    /// - `[36..44]`: seed length
+    /// - `[44..  ]`: seed (max 32)
+    /// - `[..  +8]`: lamports
+    /// - `[..  +8]`: account space
+    /// - `[.. +32]`: owner pubkey
+    pub fn instruction_data<'d>(
+        buffer: &'d mut [u8; CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN],
+        base: &Pubkey,
+        seed: &str,
+        lamports: u64,
+        space: u64,
+        owner: &Pubkey,
+    ) -> Result<&'d [u8], ProgramError> {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(MyProgramError::InvalidSeed.into());
+        }
+
+        buffer[0..4].copy_from_slice(&CREATE_ACCOUNT_WITH_SEED.to_le_bytes());
+        buffer[4..36].copy_from_slice(base);
+        buffer[36..44].copy_from_slice(&(seed.len() as u64).to_le_bytes());
+
+        let offset = 44 + seed.len();
+        buffer[44..offset].copy_from_slice(seed.as_bytes());
+        buffer[offset..offset + 8].copy_from_slice(&lamports.to_le_bytes());
+        buffer[offset + 8..offset + 16].copy_from_slice(&space.to_le_bytes());
+        buffer[offset + 16..offset + 48].copy_from_slice(owner);
+
+        Ok(&buffer[..offset + 48])
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let base = self.base.unwrap_or(self.from);
+
+        // account metadata
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable_signer(self.from.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(base.key()),
+        ];
+
+        let mut buffer = [0; CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_system::ID,
+            accounts: &account_metas,
+            data: Self::instruction_data(
+                &mut buffer,
+                base.key(),
+                self.seed,
+                self.lamports,
+                self.space,
+                self.owner,
+            )?,
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to, base], signers)
+    }
+}
+
+/// Allocate space in a (possibly new) account without funding.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` New account
+pub struct Allocate<'a> {
+    /// Account to be allocated.
+    pub account: &'a AccountInfo,
+
+    /// Number of bytes of memory to allocate.
+    pub space: u64,
+}
+
+impl Allocate<'_> {
+    /// - `[0..4 ]`: instruction discriminator
+    /// - `[4..12]`: account space
+    pub fn instruction_data(space: u64) -> [u8; 12] {
+        let mut instruction_data = [0; 12];
+        instruction_data[0..4].copy_from_slice(&ALLOCATE.to_le_bytes());
+        instruction_data[4..12].copy_from_slice(&space.to_le_bytes());
+        instruction_data
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 1] = [AccountMeta::writable_signer(self.account.key())];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_system::ID,
+            accounts: &account_metas,
+            data: &Self::instruction_data(self.space),
+        };
+
+        invoke_signed(&instruction, &[self.account], signers)
+    }
+}
+
+/// Assign account to a program
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Assigned account public key
+pub struct Assign<'a, 'b> {
+    /// Account to be assigned.
+    pub account: &'a AccountInfo,
+
+    /// Program account to assign as owner.
+    pub owner: &'b Pubkey,
+}
+
+impl Assign<'_, '_> {
+    /// - `[0..4 ]`: instruction discriminator
+    /// - `[4..36]`: owner pubkey
+    pub fn instruction_data(owner: &Pubkey) -> [u8; 36] {
+        let mut instruction_data = [0; 36];
+        instruction_data[0..4].copy_from_slice(&ASSIGN.to_le_bytes());
+        instruction_data[4..36].copy_from_slice(owner);
+        instruction_data
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 1] = [AccountMeta::writable_signer(self.account.key())];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_system::ID,
+            accounts: &account_metas,
+            data: &Self::instruction_data(self.owner),
+        };
+
+        invoke_signed(&instruction, &[self.account], signers)
+    }
+}
+
+/// Transfer lamports.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Recipient account
+pub struct Transfer<'a> {
+    /// Funding account.
+    pub from: &'a AccountInfo,
+
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+
+    /// Amount of lamports to transfer.
+    pub lamports: u64,
+}
+
+impl Transfer<'_> {
+    /// - `[0..4 ]`: instruction discriminator
+    /// - `[4..12]`: lamports
+    pub fn instruction_data(lamports: u64) -> [u8; 12] {
+        let mut instruction_data = [0; 12];
+        instruction_data[0..4].copy_from_slice(&TRANSFER.to_le_bytes());
+        instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+        instruction_data
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable_signer(self.from.key()),
+            AccountMeta::writable(self.to.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &pinocchio_system::ID,
+            accounts: &account_metas,
+            data: &Self::instruction_data(self.lamports),
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to], signers)
+    }
+}
@@ -6,23 +6,26 @@ use pinocchio::{
     // sysvars::rent::Rent,
     ProgramResult,
 };
-use pinocchio_token::instructions::CloseAccount;
+use crate::instruction::system_interface::Transfer;
 
-use pinocchio_token::{ instructions::TransferChecked, state::{ Mint, TokenAccount } };
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, validate_mint_extensions, CloseVault, TransferChecked,
+};
 
 use crate::{
     error::MyProgramError,
     state::{
+        try_from_account_info,
         utils::{load_ix_data, DataLen},
-        EscrowState,
+        EscrowConfig, EscrowState, NativeSide, OfferBook,
     },
+    validation::{check_mint, check_owner, check_program, check_signer, check_token_account, check_writable},
 };
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
 pub struct TakeEscrowIxData {
     pub taker: Pubkey,
-    pub bump: u8,
 }
 
 impl DataLen for TakeEscrowIxData {
@@ -40,33 +43,29 @@ pub fn process_take_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         taker_ata_a, 
         taker_ata_b, 
         maker_ata_b, 
-        _system_program, 
-        _token_program, 
-        _rest @..
+        system_program, 
+        token_program_a, 
+        token_program_b, 
+        config_acc, 
+        fee_recipient, 
+        vault_authority,
+        maker_ata_a,
+        rest @..
     ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    if !taker.is_signer() {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
-
-    let escrow_state = EscrowState::from_account_info(escrow_acc)?;
-
-    let vault_acc = TokenAccount::from_account_info(vault)?;
-    assert_eq!(vault_acc.owner(), escrow_acc.key());
-
-    let taker_ata_a_acc = TokenAccount::from_account_info(taker_ata_a)?;
-    assert_eq!(taker_ata_a_acc.owner(), taker.key());
+    check_signer(taker)?;
+    check_writable(taker)?;
+    check_writable(maker)?;
+    check_writable(escrow_acc)?;
+    check_writable(vault)?;
+    check_program(system_program, &pinocchio_system::ID)?;
 
-    let taker_ata_b_acc = TokenAccount::from_account_info(taker_ata_b)?;
-    assert_eq!(taker_ata_b_acc.owner(), taker.key());
+    // The escrow must be ours before its data is trusted
+    check_owner(escrow_acc, &crate::ID)?;
 
-    let maker_ata_b_acc = TokenAccount::from_account_info(maker_ata_b)?;
-    assert_eq!(maker_ata_b_acc.owner(), maker.key());
-
-    let mint_a_acc = Mint::from_account_info(mint_a)?;
-    let mint_b_acc = Mint::from_account_info(mint_b)?;
+    let escrow_state = EscrowState::from_account_info(escrow_acc)?;
 
     let ix_data = unsafe { load_ix_data::<TakeEscrowIxData>(data)? };
 
@@ -74,43 +73,169 @@ pub fn process_take_escrow(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(MyProgramError::InvalidOwner.into());
     }
 
+    escrow_state.validate_taker(taker.key())?;
+
     // Validate PDA
-    EscrowState::validate_pda(ix_data.bump, escrow_acc.key(), &maker.key())?;
+    escrow_state.validate_address(escrow_acc.key(), maker.key())?;
+
+    escrow_state.check_vault_authority(vault_authority.key(), escrow_acc.key())?;
+
+    let config = unsafe { try_from_account_info::<EscrowConfig>(config_acc)? };
+    EscrowConfig::validate_pda(config.bump, config_acc.key())?;
+
+    // The protocol fee is split off the taker's payment, the maker gets the rest
+    let fee = config.fee_for(escrow_state.receive_amount)?;
+    let maker_amount = escrow_state.receive_amount - fee;
+
+    // Pay the maker, `mint_b` and the mint B token accounts are unused when paying in SOL,
+    // in which case `fee_recipient` is the recipient wallet rather than its token account
+    if escrow_state.native_side == NativeSide::Requested {
+        Transfer {
+            from: taker,
+            to: maker,
+            lamports: maker_amount,
+        }.invoke()?;
+
+        if fee > 0 {
+            check_writable(fee_recipient)?;
+            if fee_recipient.key().ne(&config.fee_recipient) {
+                return Err(MyProgramError::InvalidFeeRecipient.into());
+            }
+
+            Transfer {
+                from: taker,
+                to: fee_recipient,
+                lamports: fee,
+            }.invoke()?;
+        }
+    } else {
+        if escrow_state.mint_b.ne(mint_b.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_b_program = token_program_of(mint_b)?;
+        if token_program_b.key().ne(mint_b_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        validate_mint_extensions(mint_b)?;
+
+        check_writable(taker_ata_b)?;
+        check_token_account(taker_ata_b, mint_b_program, mint_b.key(), taker.key())?;
+
+        check_writable(maker_ata_b)?;
+        check_token_account(maker_ata_b, mint_b_program, mint_b.key(), maker.key())?;
+
+        let mint_b_acc = check_mint(mint_b, mint_b_program)?;
+
+        TransferChecked {
+            from: taker_ata_b,
+            to: maker_ata_b,
+            authority: taker,
+            mint: mint_b,
+            amount: maker_amount,
+            decimals: mint_b_acc.decimals(),
+            token_program: mint_b_program,
+        }.invoke()?;
+
+        if fee > 0 {
+            check_writable(fee_recipient)?;
+            check_token_account(fee_recipient, mint_b_program, mint_b.key(), &config.fee_recipient)?;
+
+            TransferChecked {
+                from: taker_ata_b,
+                to: fee_recipient,
+                authority: taker,
+                mint: mint_b,
+                amount: fee,
+                decimals: mint_b_acc.decimals(),
+                token_program: mint_b_program,
+            }.invoke()?;
+        }
+    }
+
+    // Release the vault, `mint_a` and the mint A token accounts are unused for a SOL vault
+    if escrow_state.native_side == NativeSide::Offered {
+        release_sol_vault(escrow_acc, vault, taker, &escrow_state)?;
+    } else {
+        if escrow_state.mint_a.ne(mint_a.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_a_program = token_program_of(mint_a)?;
+        if token_program_a.key().ne(mint_a_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        let vault_acc = load_token_account(vault, mint_a_program)?;
+        if vault_acc.owner().ne(vault_authority.key()) {
+            return Err(MyProgramError::InvalidVaultOwner.into());
+        }
+        if vault_acc.mint().ne(mint_a.key()) {
+            return Err(MyProgramError::InvalidVaultMint.into());
+        }
+
+        check_writable(taker_ata_a)?;
+        check_token_account(taker_ata_a, mint_a_program, mint_a.key(), taker.key())?;
+
+        // Tokens sent to the vault on top of the deposit go back to the maker
+        if vault_acc.amount() > escrow_state.amount {
+            check_writable(maker_ata_a)?;
+            check_token_account(maker_ata_a, mint_a_program, mint_a.key(), maker.key())?;
+        }
+
+        let mint_a_acc = check_mint(mint_a, mint_a_program)?;
+
+        escrow_state.with_vault_signer(escrow_acc.key(), |signers| {
+            CloseVault {
+                vault,
+                mint: mint_a,
+                to: taker_ata_a,
+                excess_to: maker_ata_a,
+                authority: vault_authority,
+                destination: maker,
+                amount: escrow_state.amount,
+                decimals: mint_a_acc.decimals(),
+                token_program: mint_a_program,
+            }.invoke_signed(signers)
+        })?;
+    }
 
-    TransferChecked {
-        from: taker_ata_b,
-        to: maker_ata_b,
-        authority: taker,
-        mint: mint_b,
-        amount: escrow_state.receive_amount,
-        decimals: mint_b_acc.decimals(),
-    }.invoke()?;
+    // A listed escrow has to leave its offer book with it
+    if escrow_state.offer_slot != OfferBook::NIL {
+        let Some(offer_book) = rest.first() else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        OfferBook::delist(offer_book, escrow_acc, &escrow_state)?;
+    }
 
-    let pda_bump_bytes = [ix_data.bump];
+    // Close the escrow account, rent goes back to the maker
+    EscrowState::close(escrow_acc, maker)?;
 
-    let signer_seeds = [
-        Seed::from(EscrowState::SEED.as_bytes()),
-        Seed::from(maker.key()),
-        Seed::from(&pda_bump_bytes[..]),
-    ];
-    let signers = [Signer::from(&signer_seeds[..])];
+    Ok(())
+}
 
-    TransferChecked {
-        from: vault,
-        to: taker_ata_a,
-        authority: escrow_acc,
-        mint: mint_a,
-        amount: escrow_state.receive_amount,
-        decimals: mint_a_acc.decimals(),
-    }.invoke_signed(&signers)?;
+pub(crate) fn release_sol_vault(
+    escrow_acc: &AccountInfo,
+    sol_vault: &AccountInfo,
+    recipient: &AccountInfo,
+    escrow_state: &EscrowState,
+) -> ProgramResult {
+    EscrowState::validate_sol_vault(escrow_state.sol_vault_bump, sol_vault.key(), escrow_acc.key())?;
 
-    CloseAccount {
-        account: escrow_acc,
-        authority: escrow_acc,
-        destination: taker,
-    }.invoke_signed(&signers)?;
+    let sol_vault_bump_bytes = [escrow_state.sol_vault_bump];
 
-    EscrowState::take(escrow_acc, ix_data)?;
+    let signer_seeds = [
+        Seed::from(EscrowState::SOL_VAULT_SEED.as_bytes()),
+        Seed::from(escrow_acc.key()),
+        Seed::from(&sol_vault_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
 
-    Ok(())
+    // Drain the whole vault so the system account is reaped
+    Transfer {
+        from: sol_vault,
+        to: recipient,
+        lamports: sol_vault.lamports(),
+    }.invoke_signed(&signers)
 }
@@ -0,0 +1,179 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::instruction::token_interface::{
+    load_token_account, token_program_of, validate_mint_extensions, CloseVault, TransferChecked,
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        close_account, try_from_account_info,
+        utils::{load_ix_data, DataLen},
+        BasketEscrow,
+    },
+    validation::{check_mint, check_owner, check_program, check_signer, check_token_account, check_writable},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub struct TakeBasketIxData {
+    pub taker: Pubkey,
+}
+
+impl DataLen for TakeBasketIxData {
+    const LEN: usize = core::mem::size_of::<TakeBasketIxData>();
+}
+
+/// Accounts passed per leg after the fixed accounts. Requested legs come first
+/// as mint, taker token account, maker token account and token program, then
+/// offered legs as mint, vault, taker token account and token program. An offered
+/// leg's mint has to be writable when it charges transfer fees.
+pub const TAKE_BASKET_LEG_ACCOUNTS: usize = 4;
+
+pub fn process_take_basket(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        taker,
+        maker,
+        basket_acc,
+        system_program,
+        leg_accounts @..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_signer(taker)?;
+    check_writable(taker)?;
+    check_writable(maker)?;
+    check_writable(basket_acc)?;
+    check_program(system_program, &pinocchio_system::ID)?;
+
+    // The basket must be ours before its data is trusted
+    check_owner(basket_acc, &crate::ID)?;
+
+    let basket = unsafe { *try_from_account_info::<BasketEscrow>(basket_acc)? };
+
+    let ix_data = unsafe { load_ix_data::<TakeBasketIxData>(data)? };
+
+    if ix_data.taker.ne(taker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    if basket.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    BasketEscrow::validate_pda(basket.bump, basket_acc.key(), maker.key())?;
+
+    let requested_count = basket.requested_count as usize;
+    let offered_count = basket.offered_count as usize;
+
+    if leg_accounts.len() < (requested_count + offered_count) * TAKE_BASKET_LEG_ACCOUNTS {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (requested_accounts, offered_accounts) =
+        leg_accounts.split_at(requested_count * TAKE_BASKET_LEG_ACCOUNTS);
+
+    // Pay the maker every requested leg
+    for (leg, accounts) in basket.requested[..requested_count]
+        .iter()
+        .zip(requested_accounts.chunks_exact(TAKE_BASKET_LEG_ACCOUNTS))
+    {
+        let [mint, taker_ata, maker_ata, token_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if leg.mint.ne(mint.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_program = token_program_of(mint)?;
+        if token_program.key().ne(mint_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        validate_mint_extensions(mint)?;
+
+        check_writable(taker_ata)?;
+        check_token_account(taker_ata, mint_program, mint.key(), taker.key())?;
+
+        check_writable(maker_ata)?;
+        check_token_account(maker_ata, mint_program, mint.key(), maker.key())?;
+
+        let mint_acc = check_mint(mint, mint_program)?;
+
+        TransferChecked {
+            from: taker_ata,
+            to: maker_ata,
+            authority: taker,
+            mint,
+            amount: leg.amount,
+            decimals: mint_acc.decimals(),
+            token_program: mint_program,
+        }.invoke()?;
+    }
+
+    let pda_bump_bytes = [basket.bump];
+
+    let signer_seeds = [
+        Seed::from(BasketEscrow::SEED.as_bytes()),
+        Seed::from(maker.key()),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    // Release every offered leg to the taker and close its vault
+    for (leg, accounts) in basket.offered[..offered_count]
+        .iter()
+        .zip(offered_accounts.chunks_exact(TAKE_BASKET_LEG_ACCOUNTS))
+    {
+        let [mint, vault, taker_ata, token_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if leg.mint.ne(mint.key()) {
+            return Err(MyProgramError::MintMismatch.into());
+        }
+
+        let mint_program = token_program_of(mint)?;
+        if token_program.key().ne(mint_program) {
+            return Err(MyProgramError::InvalidTokenProgram.into());
+        }
+
+        check_writable(vault)?;
+        let vault_acc = load_token_account(vault, mint_program)?;
+        if vault_acc.owner().ne(basket_acc.key()) {
+            return Err(MyProgramError::InvalidVaultOwner.into());
+        }
+        if vault_acc.mint().ne(mint.key()) {
+            return Err(MyProgramError::InvalidVaultMint.into());
+        }
+
+        check_writable(taker_ata)?;
+        check_token_account(taker_ata, mint_program, mint.key(), taker.key())?;
+
+        let mint_acc = check_mint(mint, mint_program)?;
+
+        // The taker gets the vault's whole balance, tokens sent to it included
+        CloseVault {
+            vault,
+            mint,
+            to: taker_ata,
+            excess_to: taker_ata,
+            authority: basket_acc,
+            destination: maker,
+            amount: leg.amount,
+            decimals: mint_acc.decimals(),
+            token_program: mint_program,
+        }.invoke_signed(&signers)?;
+    }
+
+    close_account(basket_acc, maker)?;
+
+    Ok(())
+}
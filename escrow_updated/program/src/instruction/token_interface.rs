@@ -0,0 +1,372 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+use crate::{error::MyProgramError, validation::check_writable};
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// Token-2022 pads mints to the token account size, then writes the account type
+// byte; extensions are TLV entries (u16 type, u16 length, value) after that.
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+const EXTENSIONS_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+// Token-2022 extension types the escrow can't hold safely.
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_TRANSFER_HOOK: u16 = 14;
+
+// Token account extension holding the transfer fees withheld in the account.
+const EXTENSION_TRANSFER_FEE_AMOUNT: u16 = 2;
+
+/// Returns the token program owning `mint`, either SPL Token or Token-2022.
+pub fn token_program_of(mint: &AccountInfo) -> Result<&'static Pubkey, ProgramError> {
+    if mint.is_owned_by(&pinocchio_token::ID) {
+        Ok(&pinocchio_token::ID)
+    } else if mint.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        Ok(&TOKEN_2022_PROGRAM_ID)
+    } else {
+        Err(MyProgramError::InvalidTokenProgram.into())
+    }
+}
+
+/// Loads the base mint state of a mint owned by `token_program`, ignoring any
+/// Token-2022 extension data that follows it.
+pub fn load_mint<'a>(mint: &'a AccountInfo, token_program: &Pubkey) -> Result<&'a Mint, ProgramError> {
+    if !mint.is_owned_by(token_program) {
+        return Err(MyProgramError::InvalidTokenProgram.into());
+    }
+    if mint.data_len() < Mint::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(unsafe { Mint::from_bytes(&mint.borrow_data_unchecked()[..Mint::LEN]) })
+}
+
+/// Loads the base token account state of an account owned by `token_program`,
+/// ignoring any Token-2022 extension data that follows it.
+pub fn load_token_account<'a>(
+    account: &'a AccountInfo,
+    token_program: &Pubkey,
+) -> Result<&'a TokenAccount, ProgramError> {
+    if !account.is_owned_by(token_program) {
+        return Err(MyProgramError::InvalidTokenProgram.into());
+    }
+    if account.data_len() < TokenAccount::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(unsafe { TokenAccount::from_bytes(&account.borrow_data_unchecked()[..TokenAccount::LEN]) })
+}
+
+/// Rejects Token-2022 mints carrying extensions that would let the vault
+/// balance be moved or frozen outside of the escrow.
+pub fn validate_mint_extensions(mint: &AccountInfo) -> ProgramResult {
+    if !mint.is_owned_by(&TOKEN_2022_PROGRAM_ID) || mint.data_len() <= EXTENSIONS_OFFSET {
+        return Ok(());
+    }
+
+    let data = unsafe { mint.borrow_data_unchecked() };
+    let mut offset = EXTENSIONS_OFFSET;
+
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let extension_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+        match extension_type {
+            // uninitialized padding, no more extensions
+            0 => break,
+            EXTENSION_NON_TRANSFERABLE | EXTENSION_PERMANENT_DELEGATE | EXTENSION_TRANSFER_HOOK => {
+                return Err(MyProgramError::UnsupportedMintExtension.into());
+            }
+            _ => {}
+        }
+
+        offset += 4 + extension_len;
+    }
+
+    Ok(())
+}
+
+/// Returns the transfer fees withheld in a Token-2022 token account, 0 for any
+/// account without the transfer fee extension.
+pub fn withheld_transfer_fees(account: &AccountInfo) -> u64 {
+    if !account.is_owned_by(&TOKEN_2022_PROGRAM_ID) || account.data_len() <= EXTENSIONS_OFFSET {
+        return 0;
+    }
+
+    let data = unsafe { account.borrow_data_unchecked() };
+    let mut offset = EXTENSIONS_OFFSET;
+
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let extension_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+        match extension_type {
+            0 => break,
+            EXTENSION_TRANSFER_FEE_AMOUNT => {
+                let mut withheld = [0; 8];
+                if let Some(value) = data.get(offset + 4..offset + 12) {
+                    withheld.copy_from_slice(value);
+                }
+                return u64::from_le_bytes(withheld);
+            }
+            _ => {}
+        }
+
+        offset += 4 + extension_len;
+    }
+
+    0
+}
+
+/// Empty a vault and close it through either token program.
+///
+/// `amount` goes to `to` and whatever else the vault holds goes to `excess_to`,
+/// so tokens sent to the vault's address can't keep it from closing. Transfer
+/// fees withheld in a Token-2022 vault block the close the same way and are
+/// harvested to the mint first, which then has to be writable.
+pub struct CloseVault<'a, 'b> {
+    /// Vault token account.
+    pub vault: &'a AccountInfo,
+
+    /// Mint account.
+    pub mint: &'a AccountInfo,
+
+    /// Recipient of `amount`.
+    pub to: &'a AccountInfo,
+
+    /// Recipient of the tokens held on top of `amount`, may be `to`.
+    pub excess_to: &'a AccountInfo,
+
+    /// Vault owner.
+    pub authority: &'a AccountInfo,
+
+    /// Recipient of the vault's rent.
+    pub destination: &'a AccountInfo,
+
+    /// Amount of micro-tokens the vault holds for `to`.
+    pub amount: u64,
+
+    /// Decimal for the mint.
+    pub decimals: u8,
+
+    /// Token program owning the mint.
+    pub token_program: &'b Pubkey,
+}
+
+impl CloseVault<'_, '_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let balance = load_token_account(self.vault, self.token_program)?.amount();
+        let withheld = withheld_transfer_fees(self.vault);
+
+        let excess = balance
+            .checked_sub(self.amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+
+        let (amount, excess) = if self.excess_to.key() == self.to.key() {
+            (balance, 0)
+        } else {
+            (self.amount, excess)
+        };
+
+        TransferChecked {
+            from: self.vault,
+            mint: self.mint,
+            to: self.to,
+            authority: self.authority,
+            amount,
+            decimals: self.decimals,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)?;
+
+        if excess > 0 {
+            TransferChecked {
+                from: self.vault,
+                mint: self.mint,
+                to: self.excess_to,
+                authority: self.authority,
+                amount: excess,
+                decimals: self.decimals,
+                token_program: self.token_program,
+            }
+            .invoke_signed(signers)?;
+        }
+
+        if withheld > 0 {
+            check_writable(self.mint)?;
+            HarvestWithheldTokensToMint {
+                mint: self.mint,
+                account: self.vault,
+            }
+            .invoke()?;
+        }
+
+        CloseAccount {
+            account: self.vault,
+            destination: self.destination,
+            authority: self.authority,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
+
+/// Move the transfer fees withheld in a Token-2022 account to its mint, anyone
+/// can sign for it.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint.
+///   1. `[WRITE]` The account holding the withheld fees.
+pub struct HarvestWithheldTokensToMint<'a> {
+    /// Mint account.
+    pub mint: &'a AccountInfo,
+
+    /// Token account.
+    pub account: &'a AccountInfo,
+}
+
+impl HarvestWithheldTokensToMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::writable(self.account.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            // transfer fee extension '26', harvest withheld tokens to mint '4'
+            data: &[26, 4],
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.account], &[])
+    }
+}
+
+/// Transfer tokens with a mint and decimals check through either token program.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+pub struct TransferChecked<'a, 'b> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+
+    /// Mint account.
+    pub mint: &'a AccountInfo,
+
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+
+    /// Authority account.
+    pub authority: &'a AccountInfo,
+
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+
+    /// Decimal for the mint.
+    pub decimals: u8,
+
+    /// Token program owning the mint.
+    pub token_program: &'b Pubkey,
+}
+
+impl TransferChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.from.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // instruction data
+        // - [0  ]: instruction discriminator
+        // - [1..9]: amount
+        // - [9  ]: decimals
+        let mut instruction_data = [0; 10];
+        instruction_data[0] = 12;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.from, self.mint, self.to, self.authority],
+            signers,
+        )
+    }
+}
+
+/// Close a token account through either token program.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to close.
+///   1. `[WRITE]` The destination account.
+///   2. `[SIGNER]` The account's owner.
+pub struct CloseAccount<'a, 'b> {
+    /// Token Account.
+    pub account: &'a AccountInfo,
+
+    /// Destination Account.
+    pub destination: &'a AccountInfo,
+
+    /// Owner Account.
+    pub authority: &'a AccountInfo,
+
+    /// Token program owning the account.
+    pub token_program: &'b Pubkey,
+}
+
+impl CloseAccount<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable(self.destination.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            // close account instruction has a '9' discriminator
+            data: &[9],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.account, self.destination, self.authority],
+            signers,
+        )
+    }
+}
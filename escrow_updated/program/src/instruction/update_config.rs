@@ -0,0 +1,47 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        try_from_account_info_mut,
+        utils::{load_ix_data, DataLen},
+        EscrowConfig,
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub struct UpdateConfigIxData {
+    pub fee_recipient: Pubkey,
+    pub fee_bps: u16,
+}
+
+impl DataLen for UpdateConfigIxData {
+    const LEN: usize = core::mem::size_of::<UpdateConfigIxData>();
+}
+
+pub fn process_update_config(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin, config_acc, _rest @..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let config = unsafe { try_from_account_info_mut::<EscrowConfig>(config_acc)? };
+
+    EscrowConfig::validate_pda(config.bump, config_acc.key())?;
+
+    if config.admin.ne(admin.key()) {
+        return Err(MyProgramError::Unauthorized.into());
+    }
+
+    let ix_data = unsafe { load_ix_data::<UpdateConfigIxData>(data)? };
+
+    EscrowConfig::validate_fee_bps(ix_data.fee_bps)?;
+
+    config.update(ix_data)?;
+
+    Ok(())
+}
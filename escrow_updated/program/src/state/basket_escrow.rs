@@ -0,0 +1,89 @@
+use super::utils::{load_acc_mut_unchecked, DataLen};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    ProgramResult,
+};
+
+use crate::{error::MyProgramError, instruction::MakeBasketIxData};
+
+/// One mint and amount of a basket trade.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, shank::ShankType)]
+pub struct Leg {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Escrow for an N-for-M token swap, a header followed by the offered and
+/// requested legs. Only the first `offered_count`/`requested_count` legs are used.
+#[repr(C)] //keeps the struct layout the same across different architectures
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct BasketEscrow {
+    pub maker: Pubkey,
+    pub offered_count: u8,
+    pub requested_count: u8,
+    pub bump: u8,
+    pub _padding: [u8; 5],
+    // shank only reads literal array lengths, keep in step with MAX_LEGS
+    /// Offered legs, amounts net of any Token-2022 transfer fee.
+    pub offered: [Leg; 4],
+    pub requested: [Leg; 4],
+}
+
+impl DataLen for BasketEscrow {
+    const LEN: usize = core::mem::size_of::<BasketEscrow>();
+}
+
+impl BasketEscrow {
+    pub const SEED: &'static str = "basket";
+
+    pub const MAX_LEGS: usize = 4;
+
+    pub fn validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), owner, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *pda {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Checks a leg count is between 1 and `MAX_LEGS`.
+    pub fn validate_leg_count(count: u8) -> Result<usize, ProgramError> {
+        let count = count as usize;
+        if count == 0 || count > Self::MAX_LEGS {
+            return Err(MyProgramError::InvalidLegCount.into());
+        }
+        Ok(count)
+    }
+
+    /// Rejects a side listing the same mint twice, its legs would share a vault.
+    pub fn validate_unique_mints(legs: &[Leg]) -> ProgramResult {
+        for (i, leg) in legs.iter().enumerate() {
+            if legs[i + 1..].iter().any(|other| other.mint.eq(&leg.mint)) {
+                return Err(MyProgramError::DuplicateLegMint.into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn make(
+        basket_acc: &AccountInfo,
+        ix_data: &MakeBasketIxData,
+        offered: &[Leg; BasketEscrow::MAX_LEGS],
+        bump: u8,
+    ) -> ProgramResult {
+        let basket = unsafe { load_acc_mut_unchecked::<BasketEscrow>(basket_acc.borrow_mut_data_unchecked()) }?;
+
+        basket.maker = ix_data.maker;
+        basket.offered_count = ix_data.offered_count;
+        basket.requested_count = ix_data.requested_count;
+        basket.bump = bump;
+        basket.offered = *offered;
+        basket.requested = ix_data.requested;
+
+        Ok(())
+    }
+}
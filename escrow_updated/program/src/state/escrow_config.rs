@@ -0,0 +1,82 @@
+use super::utils::{load_acc_mut_unchecked, DataLen};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    ProgramResult,
+};
+
+use crate::{
+    error::MyProgramError,
+    instruction::{InitializeConfigIxData, UpdateConfigIxData},
+};
+
+#[repr(C)] //keeps the struct layout the same across different architectures
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct EscrowConfig {
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+    pub _padding: [u8; 1],
+}
+
+impl DataLen for EscrowConfig {
+    const LEN: usize = core::mem::size_of::<EscrowConfig>();
+}
+
+impl EscrowConfig {
+    pub const SEED: &'static str = "escrow_config";
+
+    /// Hard cap on the protocol fee, 5%.
+    pub const MAX_FEE_BPS: u16 = 500;
+
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    pub fn validate_pda(bump: u8, pda: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *pda {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn validate_fee_bps(fee_bps: u16) -> Result<(), ProgramError> {
+        if fee_bps > Self::MAX_FEE_BPS {
+            return Err(MyProgramError::FeeTooHigh.into());
+        }
+        Ok(())
+    }
+
+    /// Fee taken out of `amount`, rounded down.
+    pub fn fee_for(&self, amount: u64) -> Result<u64, ProgramError> {
+        amount
+            .checked_mul(self.fee_bps as u64)
+            .map(|scaled| scaled / Self::BPS_DENOMINATOR)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn initialize(
+        config_acc: &AccountInfo,
+        admin: &Pubkey,
+        ix_data: &InitializeConfigIxData,
+        bump: u8,
+    ) -> ProgramResult {
+        let config = unsafe { load_acc_mut_unchecked::<EscrowConfig>(config_acc.borrow_mut_data_unchecked()) }?;
+
+        config.admin = *admin;
+        config.fee_recipient = ix_data.fee_recipient;
+        config.fee_bps = ix_data.fee_bps;
+        config.bump = bump;
+
+        Ok(())
+    }
+
+    pub fn update(&mut self, ix_data: &UpdateConfigIxData) -> ProgramResult {
+        self.fee_recipient = ix_data.fee_recipient;
+        self.fee_bps = ix_data.fee_bps;
+
+        Ok(())
+    }
+}
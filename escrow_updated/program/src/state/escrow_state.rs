@@ -1,6 +1,10 @@
-use super::utils::{load_acc_mut_unchecked, load_acc_unchecked, DataLen};
+use super::{
+    utils::{close_account, create_with_seed, load_acc_mut_unchecked, load_acc_unchecked, DataLen},
+    OfferBook,
+};
 use pinocchio::{
     account_info::AccountInfo,
+    instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey::{self, Pubkey},
     ProgramResult,
@@ -8,17 +12,59 @@ use pinocchio::{
 
 use crate::{
     error::MyProgramError,
-    instruction::{MakeEscrowIxData, TakeEscrowIxData},
+    instruction::MakeEscrowIxData,
 };
 
+/// Which side of the trade, if any, is settled in native lamports instead of an SPL mint.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub enum NativeSide {
+    None,
+    // maker deposits SOL into the SOL vault PDA
+    Offered,
+    // taker pays the maker in SOL
+    Requested,
+}
+
+impl TryFrom<u8> for NativeSide {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(NativeSide::None),
+            1 => Ok(NativeSide::Offered),
+            2 => Ok(NativeSide::Requested),
+            _ => Err(MyProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
 #[repr(C)] //keeps the struct layout the same across different architectures
 #[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
 pub struct EscrowState {
     pub maker: Pubkey,
+    /// Offered mint, `Pubkey::default()` when SOL is offered.
     pub mint_a: Pubkey,
+    /// Requested mint, `Pubkey::default()` when SOL is requested.
     pub mint_b: Pubkey,
+    /// Only this key may fill the offer; `Pubkey::default()` leaves it open to anyone.
+    pub designated_taker: Pubkey,
+    /// Seed of an escrow created at `create_with_seed(maker, seed, program_id)`, see `seed_len`.
+    pub seed: [u8; 32],
+    /// Amount of mint A held in the vault, net of any Token-2022 transfer fee.
+    pub amount: u64,
     pub receive_amount: u64,
+    pub native_side: NativeSide,
+    /// Canonical bump of the PDA signing for the vault, the escrow itself or, for a
+    /// seeded escrow, its vault authority. Found once at make.
     pub bump: u8,
+    pub sol_vault_bump: u8,
+    /// Length of `seed`, 0 for an escrow living at its PDA.
+    pub seed_len: u8,
+    /// Slot in the pair's `OfferBook`, `OfferBook::NIL` when the escrow isn't listed.
+    pub offer_slot: u16,
+    /// `repr(C)` padding, spelled out so the IDL serializes `LEN` bytes.
+    pub _padding: [u8; 2],
 }
 
 impl DataLen for EscrowState {
@@ -33,6 +79,8 @@ impl DataLen for EscrowState {
 
 impl EscrowState {
     pub const SEED: &'static str = "escrow";
+    pub const SOL_VAULT_SEED: &'static str = "sol_vault";
+    pub const VAULT_AUTHORITY_SEED: &'static str = "vault_authority";
 
     pub fn from_account_info(escrow_acc: &AccountInfo) -> Result<Self, ProgramError> {
         let data = escrow_acc.try_borrow_data()?;
@@ -43,35 +91,138 @@ impl EscrowState {
 
     pub fn validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
         let seed_with_bump = &[Self::SEED.as_bytes(), owner, &[bump]];
-        let (derived, _) = pubkey::find_program_address(seed_with_bump, &crate::ID);
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
         if derived != *pda {
             return Err(MyProgramError::PdaMismatch.into());
         }
         Ok(())
     }
 
+    /// `validate_pda` re-deriving the canonical bump, only built to bench it against the stored one.
+    #[cfg(feature = "find-pda")]
+    pub fn find_and_validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
+        let (derived, canonical_bump) =
+            pubkey::find_program_address(&[Self::SEED.as_bytes(), owner], &crate::ID);
+        if derived != *pda || canonical_bump != bump {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Seeded escrows can't sign, so a PDA of the escrow owns their token vault instead.
+    pub fn validate_vault_authority(bump: u8, authority: &Pubkey, escrow: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::VAULT_AUTHORITY_SEED.as_bytes(), escrow, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *authority {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn is_seeded(&self) -> bool {
+        self.seed_len > 0
+    }
+
+    pub fn seed(&self) -> Result<&[u8], ProgramError> {
+        self.seed
+            .get(..self.seed_len as usize)
+            .ok_or(MyProgramError::InvalidSeed.into())
+    }
+
+    /// Checks the escrow sits at its maker's PDA or, when seeded, at the address its seed derives.
+    pub fn validate_address(&self, escrow: &Pubkey, maker: &Pubkey) -> Result<(), ProgramError> {
+        if !self.is_seeded() {
+            #[cfg(feature = "find-pda")]
+            return Self::find_and_validate_pda(self.bump, escrow, maker);
+            #[cfg(not(feature = "find-pda"))]
+            return Self::validate_pda(self.bump, escrow, maker);
+        }
+        if create_with_seed(maker, self.seed()?, &crate::ID)?.ne(escrow) {
+            return Err(MyProgramError::SeedAddressMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Checks the account owning the vault: the escrow itself, or its vault authority when seeded.
+    pub fn check_vault_authority(&self, vault_authority: &Pubkey, escrow: &Pubkey) -> Result<(), ProgramError> {
+        if self.is_seeded() {
+            return Self::validate_vault_authority(self.bump, vault_authority, escrow);
+        }
+        if vault_authority.ne(escrow) {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Runs `f` with the signer seeds of the PDA owning the vault.
+    pub fn with_vault_signer<F>(&self, escrow: &Pubkey, f: F) -> ProgramResult
+    where
+        F: FnOnce(&[Signer]) -> ProgramResult,
+    {
+        let pda_bump_bytes = [self.bump];
+
+        if self.is_seeded() {
+            let signer_seeds = [
+                Seed::from(Self::VAULT_AUTHORITY_SEED.as_bytes()),
+                Seed::from(escrow),
+                Seed::from(&pda_bump_bytes[..]),
+            ];
+            f(&[Signer::from(&signer_seeds[..])])
+        } else {
+            let signer_seeds = [
+                Seed::from(Self::SEED.as_bytes()),
+                Seed::from(&self.maker),
+                Seed::from(&pda_bump_bytes[..]),
+            ];
+            f(&[Signer::from(&signer_seeds[..])])
+        }
+    }
+
+    pub fn validate_sol_vault(bump: u8, sol_vault: &Pubkey, escrow: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SOL_VAULT_SEED.as_bytes(), escrow, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *sol_vault {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn validate_taker(&self, taker: &Pubkey) -> Result<(), ProgramError> {
+        if self.designated_taker != Pubkey::default() && self.designated_taker.ne(taker) {
+            return Err(MyProgramError::InvalidTaker.into());
+        }
+        Ok(())
+    }
+
     pub fn make(
         escrow_acc: &AccountInfo,
         ix_data: &MakeEscrowIxData,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
+        amount: u64,
+        bump: u8,
+        sol_vault_bump: u8,
     ) -> ProgramResult {
         let escrow_state = unsafe { load_acc_mut_unchecked::<EscrowState>(escrow_acc.borrow_mut_data_unchecked()) }?;
 
         escrow_state.maker = ix_data.maker;
+        escrow_state.mint_a = *mint_a;
+        escrow_state.mint_b = *mint_b;
+        escrow_state.designated_taker = ix_data.designated_taker;
+        escrow_state.seed = ix_data.seed;
+        escrow_state.amount = amount;
         escrow_state.receive_amount = ix_data.receive_amount;
-        escrow_state.bump = ix_data.bump;
+        escrow_state.native_side = NativeSide::try_from(ix_data.native_side)?;
+        escrow_state.bump = bump;
+        escrow_state.sol_vault_bump = sol_vault_bump;
+        escrow_state.seed_len = ix_data.seed_len;
+        escrow_state.offer_slot = OfferBook::NIL;
 
         Ok(())
     }
 
-    pub fn take(
-        escrow_acc: &AccountInfo,
-        _ix_data: &TakeEscrowIxData,
-    ) -> ProgramResult {
-        // let escrow_state = unsafe { load_acc_mut_unchecked::<EscrowState>(escrow_acc.borrow_mut_data_unchecked()) }?;
-
-        let mut data = escrow_acc.try_borrow_mut_data()?;
-        data.fill(0);
-
-        Ok(())
+    /// Wipes the escrow and returns its rent to `destination`.
+    pub fn close(escrow_acc: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+        close_account(escrow_acc, destination)
     }
 }
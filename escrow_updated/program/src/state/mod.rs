@@ -1,5 +1,11 @@
 pub mod escrow_state;
+pub mod escrow_config;
+pub mod offer_book;
+pub mod basket_escrow;
 pub mod utils;
 
 pub use escrow_state::*;
+pub use escrow_config::*;
+pub use offer_book::*;
+pub use basket_escrow::*;
 pub use utils::*;
@@ -0,0 +1,161 @@
+use super::{
+    try_from_account_info_mut,
+    utils::{load_acc_mut_unchecked, DataLen},
+    EscrowState,
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    ProgramResult,
+};
+
+use crate::{error::MyProgramError, validation::check_writable};
+
+/// One slot of an offer book, either an open escrow or a link in the free list.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankType)]
+pub struct Offer {
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub amount: u64,
+    pub receive_amount: u64,
+    /// Next free slot while this one is free, `OfferBook::NIL` at the end of the list.
+    pub next_free: u16,
+    pub is_open: u8,
+    pub _padding: [u8; 5],
+}
+
+/// Index of the open escrows for one mint pair, so takers can find offers
+/// without knowing the makers. SOL sides use the system program key as the mint.
+#[repr(C)] //keeps the struct layout the same across different architectures
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct OfferBook {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub open_offers: u16,
+    pub free_head: u16,
+    pub bump: u8,
+    pub _padding: [u8; 3],
+    // shank only reads literal array lengths, keep in step with CAPACITY
+    pub offers: [Offer; 32],
+}
+
+impl DataLen for OfferBook {
+    const LEN: usize = core::mem::size_of::<OfferBook>();
+}
+
+impl OfferBook {
+    pub const SEED: &'static str = "offer_book";
+
+    pub const CAPACITY: usize = 32;
+
+    /// Ends the free list, also stored as `EscrowState::offer_slot` for unlisted escrows.
+    pub const NIL: u16 = u16::MAX;
+
+    pub fn validate_pda(bump: u8, pda: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), mint_a, mint_b, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *pda {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn initialize(
+        offer_book_acc: &AccountInfo,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        let offer_book = unsafe { load_acc_mut_unchecked::<OfferBook>(offer_book_acc.borrow_mut_data_unchecked()) }?;
+
+        offer_book.mint_a = *mint_a;
+        offer_book.mint_b = *mint_b;
+        offer_book.open_offers = 0;
+        offer_book.bump = bump;
+
+        // Every slot starts out free, chained in index order
+        offer_book.free_head = 0;
+        for (i, offer) in offer_book.offers.iter_mut().enumerate() {
+            offer.next_free = if i + 1 < Self::CAPACITY { (i + 1) as u16 } else { Self::NIL };
+        }
+
+        Ok(())
+    }
+
+    /// Takes the head of the free list for `escrow` and returns its slot.
+    pub fn insert(&mut self, escrow: &Pubkey, escrow_state: &EscrowState) -> Result<u16, ProgramError> {
+        let slot = self.free_head;
+        if slot == Self::NIL {
+            return Err(MyProgramError::OfferBookFull.into());
+        }
+
+        let offer = &mut self.offers[slot as usize];
+        self.free_head = offer.next_free;
+
+        offer.escrow = *escrow;
+        offer.maker = escrow_state.maker;
+        offer.amount = escrow_state.amount;
+        offer.receive_amount = escrow_state.receive_amount;
+        offer.next_free = Self::NIL;
+        offer.is_open = 1;
+
+        self.open_offers += 1;
+
+        Ok(slot)
+    }
+
+    /// Clears the slot holding `escrow` and pushes it back on the free list.
+    pub fn remove(&mut self, slot: u16, escrow: &Pubkey) -> ProgramResult {
+        let offer = self
+            .offers
+            .get_mut(slot as usize)
+            .filter(|offer| offer.is_open == 1 && offer.escrow.eq(escrow))
+            .ok_or(MyProgramError::OfferNotListed)?;
+
+        *offer = Offer {
+            escrow: Pubkey::default(),
+            maker: Pubkey::default(),
+            amount: 0,
+            receive_amount: 0,
+            next_free: self.free_head,
+            is_open: 0,
+            _padding: [0; 5],
+        };
+        self.free_head = slot;
+
+        self.open_offers -= 1;
+
+        Ok(())
+    }
+
+    /// Lists a freshly made escrow in the book for its mint pair.
+    pub fn list(offer_book_acc: &AccountInfo, escrow_acc: &AccountInfo) -> ProgramResult {
+        check_writable(offer_book_acc)?;
+
+        let offer_book = unsafe { try_from_account_info_mut::<OfferBook>(offer_book_acc)? };
+        let escrow_state = unsafe { try_from_account_info_mut::<EscrowState>(escrow_acc)? };
+
+        Self::validate_pda(offer_book.bump, offer_book_acc.key(), &escrow_state.mint_a, &escrow_state.mint_b)?;
+
+        escrow_state.offer_slot = offer_book.insert(escrow_acc.key(), escrow_state)?;
+
+        Ok(())
+    }
+
+    /// Drops a listed escrow from the book, `offer_book_acc` must be the book it was listed in.
+    pub fn delist(
+        offer_book_acc: &AccountInfo,
+        escrow_acc: &AccountInfo,
+        escrow_state: &EscrowState,
+    ) -> ProgramResult {
+        check_writable(offer_book_acc)?;
+
+        let offer_book = unsafe { try_from_account_info_mut::<OfferBook>(offer_book_acc)? };
+
+        Self::validate_pda(offer_book.bump, offer_book_acc.key(), &escrow_state.mint_a, &escrow_state.mint_b)?;
+
+        offer_book.remove(escrow_state.offer_slot, escrow_acc.key())
+    }
+}
@@ -1,4 +1,8 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEED_LEN},
+};
 
 use crate::error::MyProgramError;
 
@@ -66,6 +70,51 @@ pub unsafe fn to_mut_bytes<T: DataLen>(data: &mut T) -> &mut [u8] {
     core::slice::from_raw_parts_mut(data as *mut T as *mut u8, T::LEN)
 }
 
+/// Wipes a program-owned account and moves its lamports to `destination`.
+pub fn close_account(acc: &AccountInfo, destination: &AccountInfo) -> Result<(), ProgramError> {
+    {
+        let mut data = acc.try_borrow_mut_data()?;
+        data.fill(0);
+    }
+
+    let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+    *destination_lamports = destination_lamports
+        .checked_add(acc.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    *acc.try_borrow_mut_lamports()? = 0;
+
+    Ok(())
+}
+
+/// Address the System Program's `CreateAccountWithSeed` derives, `sha256(base || seed || owner)`.
+/// Off-chain there is no sha256 syscall to derive it with, so it fails with
+/// `ProgramError::InvalidArgument`.
+pub fn create_with_seed(base: &Pubkey, seed: &[u8], owner: &Pubkey) -> Result<Pubkey, ProgramError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(MyProgramError::InvalidSeed.into());
+    }
+
+    #[cfg(target_os = "solana")]
+    {
+        let vals: [&[u8]; 3] = [base, seed, owner];
+        let mut address = [0u8; 32];
+        unsafe {
+            pinocchio::syscalls::sol_sha256(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                address.as_mut_ptr(),
+            );
+        }
+        Ok(address)
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box((base, seed, owner));
+        Err(ProgramError::InvalidArgument)
+    }
+}
+
 pub unsafe fn try_from_account_info<T: DataLen>(acc: &AccountInfo) -> Result<&T, ProgramError> {
     if acc.owner() != &crate::ID {
         return Err(ProgramError::IllegalOwner);
@@ -0,0 +1,80 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+use crate::{
+    error::MyProgramError,
+    instruction::token_interface::{load_mint, load_token_account},
+};
+
+#[inline(always)]
+pub fn check_signer(account: &AccountInfo) -> ProgramResult {
+    if !account.is_signer() {
+        return Err(MyProgramError::MissingSigner.into());
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn check_writable(account: &AccountInfo) -> ProgramResult {
+    if !account.is_writable() {
+        return Err(MyProgramError::AccountNotWritable.into());
+    }
+    Ok(())
+}
+
+/// Checks the account is owned by `program_id`.
+#[inline(always)]
+pub fn check_owner(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    if !account.is_owned_by(program_id) {
+        return Err(MyProgramError::InvalidAccountOwner.into());
+    }
+    Ok(())
+}
+
+/// Checks the account passed for a program or sysvar is the expected one.
+#[inline(always)]
+pub fn check_program(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    if account.key().ne(program_id) {
+        return Err(MyProgramError::InvalidProgramAccount.into());
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn check_pda(account: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if account.key().ne(expected) {
+        return Err(MyProgramError::PdaMismatch.into());
+    }
+    Ok(())
+}
+
+/// Loads an initialized mint owned by `token_program`, callers take the
+/// transfer decimals from the returned mint.
+pub fn check_mint<'a>(mint: &'a AccountInfo, token_program: &Pubkey) -> Result<&'a Mint, ProgramError> {
+    let mint_acc = load_mint(mint, token_program)?;
+    if !mint_acc.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(mint_acc)
+}
+
+/// Loads an initialized token account owned by `token_program` and checks it
+/// holds `mint` and belongs to `owner`.
+pub fn check_token_account<'a>(
+    account: &'a AccountInfo,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<&'a TokenAccount, ProgramError> {
+    let token_acc = load_token_account(account, token_program)?;
+    if !token_acc.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if token_acc.mint().ne(mint) {
+        return Err(MyProgramError::InvalidTokenAccountMint.into());
+    }
+    if token_acc.owner().ne(owner) {
+        return Err(MyProgramError::InvalidTokenAccountOwner.into());
+    }
+    Ok(token_acc)
+}
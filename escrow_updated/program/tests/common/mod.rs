@@ -0,0 +1,990 @@
+#![allow(dead_code)]
+
+#[path = "../../../../test_fixtures/token.rs"]
+mod token;
+pub use token::*;
+
+use mollusk_svm::{program, Mollusk};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+
+use escrow_updated::instruction::{
+    InitializeConfigIxData, MakeBasketIxData, MakeEscrowIxData, TakeBasketIxData,
+    TakeEscrowIxData, UpdateConfigIxData,
+};
+use escrow_updated::state::{
+    load_acc_mut_unchecked, to_bytes, BasketEscrow, DataLen, EscrowConfig, EscrowState, Leg,
+    NativeSide, OfferBook,
+};
+use escrow_updated::ID;
+
+pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+
+pub const ASSOCIATED_TOKEN_PROGRAM: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Escrow program with the SPL Token and Associated Token programs loaded from `tests/elfs`.
+pub fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM, "target/deploy/escrow_updated");
+    mollusk.add_program(
+        &TOKEN_PROGRAM,
+        "tests/elfs/spl_token",
+        &mollusk_svm::program::loader_keys::LOADER_V3,
+    );
+    mollusk.add_program(
+        &ASSOCIATED_TOKEN_PROGRAM,
+        "tests/elfs/spl_associated_token_account",
+        &mollusk_svm::program::loader_keys::LOADER_V2,
+    );
+    mollusk
+}
+
+pub fn associated_token_program_account() -> (Pubkey, Account) {
+    let elf = mollusk_svm::file::read_file("tests/elfs/spl_associated_token_account.so");
+    (ASSOCIATED_TOKEN_PROGRAM, program::create_program_account_loader_v2(&elf))
+}
+
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    associated_token_address_for(wallet, mint, &TOKEN_PROGRAM)
+}
+
+pub fn associated_token_address_for(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM,
+    )
+    .0
+}
+
+pub const MAKER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+
+pub const TAKER: Pubkey = pubkey!("8GRj9cW4cfWCbz4hZ5jRc4ifWJ8VQHk6pbKSx5zKkXgH");
+
+pub const MINT_A: Pubkey = pubkey!("7sQ4dZxmG3aTqzjfLVQFNqHuJjd1ZGJG6VjC9v6dHt4G");
+
+pub const MINT_B: Pubkey = pubkey!("4ZJkt7Cym6LxzjH8wd2Cg6vCTM4Hw6vGpXo9Pa3qHyDr");
+
+pub const OFFER_AMOUNT: u64 = LAMPORTS_PER_SOL;
+
+pub const RECEIVE_AMOUNT: u64 = 1_000_000;
+
+pub const ESCROW_SEED: &str = "escrow-1";
+
+pub fn escrow_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EscrowState::SEED.as_bytes(), MAKER.as_ref()], &PROGRAM)
+}
+
+/// Escrow address, vault authority and its bump for `seed`, the escrow PDA for an empty seed.
+pub fn escrow_address(seed: &str) -> (Pubkey, Pubkey, u8) {
+    if seed.is_empty() {
+        let (escrow_pda, bump) = escrow_pda();
+        return (escrow_pda, escrow_pda, bump);
+    }
+
+    let escrow = Pubkey::create_with_seed(&MAKER, seed, &PROGRAM).unwrap();
+    let (vault_authority, bump) = Pubkey::find_program_address(
+        &[EscrowState::VAULT_AUTHORITY_SEED.as_bytes(), escrow.as_ref()],
+        &PROGRAM,
+    );
+    (escrow, vault_authority, bump)
+}
+
+fn seed_bytes(seed: &str) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[..seed.len()].copy_from_slice(seed.as_bytes());
+    bytes
+}
+
+pub fn sol_vault_pda(escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[EscrowState::SOL_VAULT_SEED.as_bytes(), escrow.as_ref()],
+        &PROGRAM,
+    )
+}
+
+/// Escrow config charging no fee.
+pub fn config_account(mollusk: &Mollusk, fee_recipient: &Pubkey) -> (Pubkey, Account) {
+    let (config_pda, bump) =
+        Pubkey::find_program_address(&[EscrowConfig::SEED.as_bytes()], &PROGRAM);
+
+    let config = EscrowConfig {
+        admin: *MAKER.as_array(),
+        fee_recipient: *fee_recipient.as_array(),
+        fee_bps: 0,
+        bump,
+        _padding: [0; 1],
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(EscrowConfig::LEN),
+        EscrowConfig::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&config).to_vec() };
+
+    (config_pda, account)
+}
+
+/// Fee charged by the config helpers that take one, 1%.
+pub const FEE_BPS: u16 = 100;
+
+pub const BPF_LOADER_UPGRADEABLE: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Program data account of the escrow program, upgradeable by `upgrade_authority`.
+pub fn program_data_account(mollusk: &Mollusk, upgrade_authority: &Pubkey) -> (Pubkey, Account) {
+    let (program_data, _) =
+        Pubkey::find_program_address(&[PROGRAM.as_ref()], &BPF_LOADER_UPGRADEABLE);
+
+    // State tag 3, deploy slot 0, then the upgrade authority as an option
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(data.len()),
+        data.len(),
+        &BPF_LOADER_UPGRADEABLE,
+    );
+    account.data = data;
+
+    (program_data, account)
+}
+
+/// InitializeConfig by `MAKER`, the program's upgrade authority, charging `fee_bps`.
+pub fn initialize_config(mollusk: &Mollusk, fee_bps: u16) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (config_pda, _) = Pubkey::find_program_address(&[EscrowConfig::SEED.as_bytes()], &PROGRAM);
+    let (program_data, program_data_account) = program_data_account(mollusk, &MAKER);
+
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(program_data, false),
+    ];
+
+    let ix_data = InitializeConfigIxData { fee_recipient: *TAKER.as_array(), fee_bps };
+
+    // Ix discriminator = 2
+    let mut ser_ix_data = vec![2];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (config_pda, system_account(0)),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (program_data, program_data_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// UpdateConfig by `MAKER`, the admin of `config_account`, to `fee_recipient` and `fee_bps`.
+pub fn update_config(
+    mollusk: &Mollusk,
+    fee_recipient: &Pubkey,
+    fee_bps: u16,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (config_pda, config_account) = config_account(mollusk, &Pubkey::new_unique());
+
+    let ix_accounts = vec![AccountMeta::new(MAKER, true), AccountMeta::new(config_pda, false)];
+
+    let ix_data = UpdateConfigIxData { fee_recipient: *fee_recipient.as_array(), fee_bps };
+
+    // Ix discriminator = 3
+    let mut ser_ix_data = vec![3];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (config_pda, config_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+pub fn offer_book_pda(mint_a: &Pubkey, mint_b: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OfferBook::SEED.as_bytes(), mint_a.as_ref(), mint_b.as_ref()],
+        &PROGRAM,
+    )
+}
+
+/// Offer book for the SOL for `MINT_B` pair with `listed` escrows in its first slots.
+pub fn offer_book_account(mollusk: &Mollusk, listed: &[Pubkey]) -> (Pubkey, Account) {
+    let (offer_book, bump) = offer_book_pda(&Pubkey::default(), &MINT_B);
+
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(OfferBook::LEN),
+        OfferBook::LEN,
+        &PROGRAM,
+    );
+
+    let book = unsafe { load_acc_mut_unchecked::<OfferBook>(&mut account.data).unwrap() };
+    book.mint_b = *MINT_B.as_array();
+    book.bump = bump;
+    book.open_offers = listed.len() as u16;
+    book.free_head = match listed.len() {
+        len if len < OfferBook::CAPACITY => len as u16,
+        _ => OfferBook::NIL,
+    };
+
+    for (i, offer) in book.offers.iter_mut().enumerate() {
+        match listed.get(i) {
+            Some(escrow) => {
+                offer.escrow = *escrow.as_array();
+                offer.maker = *MAKER.as_array();
+                offer.amount = OFFER_AMOUNT;
+                offer.receive_amount = RECEIVE_AMOUNT;
+                offer.next_free = OfferBook::NIL;
+                offer.is_open = 1;
+            }
+            None if i + 1 < OfferBook::CAPACITY => offer.next_free = (i + 1) as u16,
+            None => offer.next_free = OfferBook::NIL,
+        }
+    }
+
+    (offer_book, account)
+}
+
+/// InitializeOfferBook for the SOL for `MINT_B` pair, paid by `MAKER`.
+pub fn initialize_offer_book(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (offer_book, _) = offer_book_pda(&system_program, &MINT_B);
+
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new(offer_book, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(MINT_B, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+    ];
+
+    // Ix discriminator = 5
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[5], ix_accounts);
+
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (offer_book, system_account(0)),
+        (system_program, system_program_account),
+        (MINT_B, mint_account(mollusk, &MAKER, 6)),
+        (RENT, rent_account(mollusk)),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// Open escrow made by `MAKER`, offering SOL for `MINT_B` or `MINT_A` for SOL.
+pub fn escrow_account(mollusk: &Mollusk, native_side: NativeSide) -> (Pubkey, Account) {
+    escrow_account_with_seed(mollusk, native_side, "")
+}
+
+/// Same as `escrow_account`, created with `seed` unless it's empty.
+pub fn escrow_account_with_seed(
+    mollusk: &Mollusk,
+    native_side: NativeSide,
+    seed: &str,
+) -> (Pubkey, Account) {
+    let (escrow, _, bump) = escrow_address(seed);
+    let (_, sol_vault_bump) = sol_vault_pda(&escrow);
+
+    let (mint_a, mint_b) = match native_side {
+        NativeSide::Offered => ([0; 32], *MINT_B.as_array()),
+        _ => (*MINT_A.as_array(), [0; 32]),
+    };
+
+    let escrow_state = EscrowState {
+        maker: *MAKER.as_array(),
+        mint_a,
+        mint_b,
+        designated_taker: [0; 32],
+        seed: seed_bytes(seed),
+        amount: OFFER_AMOUNT,
+        receive_amount: RECEIVE_AMOUNT,
+        native_side,
+        bump,
+        sol_vault_bump,
+        seed_len: seed.len() as u8,
+        offer_slot: OfferBook::NIL,
+        _padding: [0; 2],
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(EscrowState::LEN),
+        EscrowState::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&escrow_state).to_vec() };
+
+    (escrow, account)
+}
+
+/// MakeEscrow offering `OFFER_AMOUNT` lamports for `MINT_B`.
+pub fn make_sol_offer(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    make_sol_offer_with_seed(mollusk, "")
+}
+
+/// Same as `make_sol_offer`, creating the escrow with `seed` unless it's empty.
+pub fn make_sol_offer_with_seed(
+    mollusk: &Mollusk,
+    seed: &str,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow, vault_authority, _) = escrow_address(seed);
+    let (sol_vault, _) = sol_vault_pda(&escrow);
+
+    // Unused when SOL is offered
+    let mint_a = Pubkey::new_unique();
+    let maker_ata_a = Pubkey::new_unique();
+    let associated_token_program = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(mint_a, false),
+        AccountMeta::new_readonly(MINT_B, false),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(sol_vault, false),
+        AccountMeta::new(maker_ata_a, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(associated_token_program, false),
+        AccountMeta::new_readonly(vault_authority, false),
+    ];
+
+    // Create the instruction data
+    let ix_data = MakeEscrowIxData {
+        maker: *MAKER.as_array(),
+        designated_taker: [0; 32],
+        seed: seed_bytes(seed),
+        amount: OFFER_AMOUNT,
+        receive_amount: RECEIVE_AMOUNT,
+        native_side: NativeSide::Offered as u8,
+        seed_len: seed.len() as u8,
+        _padding: [0; 6],
+    };
+
+    // Ix discriminator = 0
+    let mut ser_ix_data = vec![0];
+
+    // Serialize the instruction data
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    // Create instruction
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    // Create tx_accounts vec
+    let mut tx_accounts = vec![
+        (MAKER, system_account(10 * LAMPORTS_PER_SOL)),
+        (mint_a, Account::default()),
+        (MINT_B, mint_account(mollusk, &MAKER, 6)),
+        (escrow, system_account(0)),
+        (sol_vault, system_account(0)),
+        (maker_ata_a, Account::default()),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+        (associated_token_program, Account::default()),
+    ];
+
+    // A PDA escrow is its own vault authority and already listed above
+    if vault_authority != escrow {
+        tx_accounts.push((vault_authority, Account::default()));
+    }
+
+    (instruction, tx_accounts)
+}
+
+pub const TOKEN_2022_PROGRAM: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+pub fn token_2022_program_account() -> (Pubkey, Account) {
+    (TOKEN_2022_PROGRAM, program::create_program_account_loader_v3(&TOKEN_2022_PROGRAM))
+}
+
+/// Token-2022 mint carrying `extensions` as (extension type, value) entries.
+pub fn token_2022_mint_account(
+    mollusk: &Mollusk,
+    authority: &Pubkey,
+    decimals: u8,
+    extensions: &[(u16, &[u8])],
+) -> Account {
+    let mut data = mint_account(mollusk, authority, decimals).data;
+
+    // Mints are padded to the token account size, then the account type (1 = mint)
+    data.resize(165, 0);
+    data.push(1);
+    for (extension_type, value) in extensions {
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(data.len()),
+        data.len(),
+        &TOKEN_2022_PROGRAM,
+    );
+    account.data = data;
+    account
+}
+
+/// Same as `mollusk`, with the Token-2022 program loaded from `tests/elfs` too.
+pub fn mollusk_token_2022() -> Mollusk {
+    let mut mollusk = mollusk();
+    mollusk.add_program(
+        &TOKEN_2022_PROGRAM,
+        "tests/elfs/spl_token_2022",
+        &mollusk_svm::program::loader_keys::LOADER_V3,
+    );
+    mollusk
+}
+
+pub const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+
+/// Value of a mint's transfer fee extension charging `basis_points` of every
+/// transfer, with no authorities and no fees withheld in the mint yet.
+pub fn transfer_fee_config(basis_points: u16) -> Vec<u8> {
+    let mut config = vec![0; 72];
+    // Older and newer transfer fee, both in effect from epoch 0
+    for _ in 0..2 {
+        config.extend_from_slice(&0u64.to_le_bytes());
+        config.extend_from_slice(&u64::MAX.to_le_bytes());
+        config.extend_from_slice(&basis_points.to_le_bytes());
+    }
+    config
+}
+
+/// Token-2022 account for a transfer fee mint, withholding `withheld` in fees.
+pub fn token_2022_account(
+    mollusk: &Mollusk,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    withheld: u64,
+) -> Account {
+    let mut data = token_account(mollusk, mint, owner, amount).data;
+
+    // Account type (2 = account), then the transfer fee amount extension
+    data.push(2);
+    data.extend_from_slice(&2u16.to_le_bytes());
+    data.extend_from_slice(&8u16.to_le_bytes());
+    data.extend_from_slice(&withheld.to_le_bytes());
+
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(data.len()),
+        data.len(),
+        &TOKEN_2022_PROGRAM,
+    );
+    account.data = data;
+    account
+}
+
+/// Withheld transfer fees of an account built by `token_2022_account`.
+pub fn withheld_amount(account: &Account) -> u64 {
+    u64::from_le_bytes(account.data[170..178].try_into().unwrap())
+}
+
+/// MakeEscrow offering `OFFER_AMOUNT` of a Token-2022 `MINT_A` charging a
+/// `FEE_BPS` transfer fee, for SOL.
+pub fn make_transfer_fee_offer(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (mut instruction, mut tx_accounts) = make_token_offer(mollusk);
+
+    let (escrow, _) = escrow_pda();
+    let vault = associated_token_address_for(&escrow, &MINT_A, &TOKEN_2022_PROGRAM);
+
+    let transfer_fee = transfer_fee_config(FEE_BPS);
+    tx_accounts[1].1 = token_2022_mint_account(
+        mollusk,
+        &MAKER,
+        6,
+        &[(EXTENSION_TRANSFER_FEE_CONFIG, &transfer_fee)],
+    );
+    instruction.accounts[4].pubkey = vault;
+    tx_accounts[4].0 = vault;
+    tx_accounts[5].1 = token_2022_account(mollusk, &MINT_A, &MAKER, OFFER_AMOUNT, 0);
+    instruction.accounts[8].pubkey = TOKEN_2022_PROGRAM;
+    tx_accounts[8] = token_2022_program_account();
+
+    (instruction, tx_accounts)
+}
+
+/// MakeEscrow offering `OFFER_AMOUNT` of `MINT_A` for SOL, the vault is created as the
+/// escrow PDA's associated token account.
+pub fn make_token_offer(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+    let (associated_token_program, associated_token_program_account) =
+        associated_token_program_account();
+
+    let (escrow, _) = escrow_pda();
+    let vault = associated_token_address(&escrow, &MINT_A);
+    let maker_ata_a = Pubkey::new_unique();
+
+    // Unused when SOL is requested
+    let mint_b = Pubkey::new_unique();
+
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(MINT_A, false),
+        AccountMeta::new_readonly(mint_b, false),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(maker_ata_a, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(associated_token_program, false),
+        AccountMeta::new_readonly(escrow, false),
+    ];
+
+    let ix_data = MakeEscrowIxData {
+        maker: *MAKER.as_array(),
+        designated_taker: [0; 32],
+        seed: [0; 32],
+        amount: OFFER_AMOUNT,
+        receive_amount: RECEIVE_AMOUNT,
+        native_side: NativeSide::Requested as u8,
+        seed_len: 0,
+        _padding: [0; 6],
+    };
+
+    let mut ser_ix_data = vec![0];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    let tx_accounts = vec![
+        (MAKER, system_account(10 * LAMPORTS_PER_SOL)),
+        (MINT_A, mint_account(mollusk, &MAKER, 6)),
+        (mint_b, Account::default()),
+        (escrow, system_account(0)),
+        (vault, system_account(0)),
+        (maker_ata_a, token_account(mollusk, &MINT_A, &MAKER, OFFER_AMOUNT)),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+        (associated_token_program, associated_token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// TakeEscrow instruction, the first account signs and the flag marks the writable ones.
+pub fn take_ix(accounts: &[(Pubkey, bool)]) -> Instruction {
+    let ix_accounts = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, (key, writable))| match (i, writable) {
+            (0, _) => AccountMeta::new(*key, true),
+            (_, true) => AccountMeta::new(*key, false),
+            (_, false) => AccountMeta::new_readonly(*key, false),
+        })
+        .collect();
+
+    let ix_data = TakeEscrowIxData {
+        taker: *TAKER.as_array(),
+    };
+
+    // Ix discriminator = 1
+    let mut ser_ix_data = vec![1];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts)
+}
+
+/// TakeEscrow for an escrow offering SOL for `MINT_B`, paid with `mint_b`.
+pub fn take_sol_offer(mollusk: &Mollusk, mint_b: Pubkey) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow_pda, escrow_account) = escrow_account(mollusk, NativeSide::Offered);
+    let (sol_vault, _) = sol_vault_pda(&escrow_pda);
+    let vault_authority = escrow_pda;
+
+    // Unused by a SOL-for-token take
+    let mint_a = Pubkey::new_unique();
+    let taker_ata_a = Pubkey::new_unique();
+    let maker_ata_a = Pubkey::new_unique();
+    let fee_recipient = Pubkey::new_unique();
+
+    let taker_ata_b = Pubkey::new_unique();
+    let maker_ata_b = Pubkey::new_unique();
+    let (config_pda, config_account) = config_account(mollusk, &fee_recipient);
+
+    let instruction = take_ix(&[
+        (TAKER, true),
+        (MAKER, true),
+        (escrow_pda, true),
+        (sol_vault, true),
+        (mint_a, true),
+        (mint_b, false),
+        (taker_ata_a, true),
+        (taker_ata_b, true),
+        (maker_ata_b, true),
+        (system_program, false),
+        (token_program, false),
+        (token_program, false),
+        (config_pda, false),
+        (fee_recipient, true),
+        (vault_authority, false),
+        (maker_ata_a, true),
+    ]);
+
+    let tx_accounts = vec![
+        (TAKER, system_account(LAMPORTS_PER_SOL)),
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (escrow_pda, escrow_account),
+        (sol_vault, system_account(OFFER_AMOUNT)),
+        (mint_a, Account::default()),
+        (mint_b, mint_account(mollusk, &MAKER, 6)),
+        (taker_ata_a, Account::default()),
+        (taker_ata_b, token_account(mollusk, &mint_b, &TAKER, RECEIVE_AMOUNT)),
+        (maker_ata_b, token_account(mollusk, &mint_b, &MAKER, 0)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+        (config_pda, config_account),
+        (fee_recipient, Account::default()),
+        (maker_ata_a, Account::default()),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// TakeEscrow for an escrow offering `MINT_A` tokens for SOL.
+pub fn take_token_offer(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    take_token_offer_with_seed(mollusk, "")
+}
+
+/// Same as `take_token_offer`, for an escrow created with `seed` unless it's empty.
+pub fn take_token_offer_with_seed(
+    mollusk: &Mollusk,
+    seed: &str,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow, escrow_account) = escrow_account_with_seed(mollusk, NativeSide::Requested, seed);
+    let (_, vault_authority, _) = escrow_address(seed);
+
+    let vault = Pubkey::new_unique();
+    let taker_ata_a = Pubkey::new_unique();
+
+    // Only receives tokens sent to the vault on top of the deposit
+    let maker_ata_a = Pubkey::new_unique();
+
+    // Unused when SOL is requested
+    let mint_b = Pubkey::new_unique();
+    let taker_ata_b = Pubkey::new_unique();
+    let maker_ata_b = Pubkey::new_unique();
+    let fee_recipient = Pubkey::new_unique();
+    let (config_pda, config_account) = config_account(mollusk, &fee_recipient);
+
+    let instruction = take_ix(&[
+        (TAKER, true),
+        (MAKER, true),
+        (escrow, true),
+        (vault, true),
+        (MINT_A, true),
+        (mint_b, false),
+        (taker_ata_a, true),
+        (taker_ata_b, true),
+        (maker_ata_b, true),
+        (system_program, false),
+        (token_program, false),
+        (token_program, false),
+        (config_pda, false),
+        (fee_recipient, true),
+        (vault_authority, false),
+        (maker_ata_a, true),
+    ]);
+
+    let mut tx_accounts = vec![
+        (TAKER, system_account(LAMPORTS_PER_SOL)),
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (escrow, escrow_account),
+        (vault, token_account(mollusk, &MINT_A, &vault_authority, OFFER_AMOUNT)),
+        (MINT_A, mint_account(mollusk, &MAKER, 6)),
+        (mint_b, Account::default()),
+        (taker_ata_a, token_account(mollusk, &MINT_A, &TAKER, 0)),
+        (taker_ata_b, Account::default()),
+        (maker_ata_b, Account::default()),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+        (config_pda, config_account),
+        (fee_recipient, Account::default()),
+        (maker_ata_a, Account::default()),
+    ];
+
+    if vault_authority != escrow {
+        tx_accounts.push((vault_authority, Account::default()));
+    }
+
+    (instruction, tx_accounts)
+}
+
+/// RefundEscrow of the escrow `escrow_account` returns for `native_side`.
+pub fn refund_escrow(mollusk: &Mollusk, native_side: NativeSide) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (escrow, escrow_account) = escrow_account(mollusk, native_side);
+    let maker_ata_a = Pubkey::new_unique();
+
+    // `MINT_A` and `maker_ata_a` are unused for a SOL vault
+    let (vault, vault_account, mint_a_account, maker_ata_a_account) = match native_side {
+        NativeSide::Offered => (
+            sol_vault_pda(&escrow).0,
+            system_account(OFFER_AMOUNT),
+            Account::default(),
+            Account::default(),
+        ),
+        _ => (
+            associated_token_address(&escrow, &MINT_A),
+            token_account(mollusk, &MINT_A, &escrow, OFFER_AMOUNT),
+            mint_account(mollusk, &MAKER, 6),
+            token_account(mollusk, &MINT_A, &MAKER, 0),
+        ),
+    };
+
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(MINT_A, false),
+        AccountMeta::new(maker_ata_a, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(escrow, false),
+    ];
+
+    // Ix discriminator = 4
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[4], ix_accounts);
+
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (escrow, escrow_account),
+        (vault, vault_account),
+        (MINT_A, mint_a_account),
+        (maker_ata_a, maker_ata_a_account),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+pub const MINT_C: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// Mints the basket helpers offer, one `OFFER_AMOUNT` leg each, for `RECEIVE_AMOUNT` of `MINT_B`.
+pub const BASKET_OFFERED: [Pubkey; 2] = [MINT_A, MINT_C];
+
+pub fn basket_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BasketEscrow::SEED.as_bytes(), MAKER.as_ref()], &PROGRAM)
+}
+
+fn legs(legs: &[(Pubkey, u64)]) -> [Leg; BasketEscrow::MAX_LEGS] {
+    let mut out = [Leg::default(); BasketEscrow::MAX_LEGS];
+    for (leg, (mint, amount)) in out.iter_mut().zip(legs) {
+        *leg = Leg { mint: *mint.as_array(), amount: *amount };
+    }
+    out
+}
+
+/// Open basket made by `MAKER`, its vaults hold the `BASKET_OFFERED` legs.
+pub fn basket_account(mollusk: &Mollusk) -> (Pubkey, Account) {
+    let (basket, bump) = basket_pda();
+
+    let offered: Vec<(Pubkey, u64)> = BASKET_OFFERED.iter().map(|mint| (*mint, OFFER_AMOUNT)).collect();
+
+    let basket_state = BasketEscrow {
+        maker: *MAKER.as_array(),
+        offered_count: BASKET_OFFERED.len() as u8,
+        requested_count: 1,
+        bump,
+        _padding: [0; 5],
+        offered: legs(&offered),
+        requested: legs(&[(MINT_B, RECEIVE_AMOUNT)]),
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(BasketEscrow::LEN),
+        BasketEscrow::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&basket_state).to_vec() };
+
+    (basket, account)
+}
+
+/// MakeBasket offering the `BASKET_OFFERED` legs for `RECEIVE_AMOUNT` of `MINT_B`.
+pub fn make_basket(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+    let (associated_token_program, associated_token_program_account) =
+        associated_token_program_account();
+
+    let (basket, _) = basket_pda();
+
+    let mut ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new(basket, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(associated_token_program, false),
+    ];
+    let mut tx_accounts = vec![
+        (MAKER, system_account(10 * LAMPORTS_PER_SOL)),
+        (basket, system_account(0)),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (associated_token_program, associated_token_program_account),
+        (token_program, token_program_account),
+    ];
+
+    for mint in BASKET_OFFERED {
+        let maker_ata = Pubkey::new_unique();
+        let vault = associated_token_address(&basket, &mint);
+
+        ix_accounts.extend([
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(maker_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(token_program, false),
+        ]);
+        tx_accounts.extend([
+            (mint, mint_account(mollusk, &MAKER, 6)),
+            (maker_ata, token_account(mollusk, &mint, &MAKER, OFFER_AMOUNT)),
+            (vault, system_account(0)),
+        ]);
+    }
+
+    let ix_data = MakeBasketIxData {
+        maker: *MAKER.as_array(),
+        offered_amounts: [OFFER_AMOUNT; BasketEscrow::MAX_LEGS],
+        requested: legs(&[(MINT_B, RECEIVE_AMOUNT)]),
+        offered_count: BASKET_OFFERED.len() as u8,
+        requested_count: 1,
+        _padding: [0; 6],
+    };
+
+    // Ix discriminator = 6
+    let mut ser_ix_data = vec![6];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    (instruction, tx_accounts)
+}
+
+/// TakeBasket paying `RECEIVE_AMOUNT` of `MINT_B` for the `basket_account` legs.
+pub fn take_basket(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (basket, basket_account) = basket_account(mollusk);
+
+    let taker_ata_b = Pubkey::new_unique();
+    let maker_ata_b = Pubkey::new_unique();
+
+    let mut ix_accounts = vec![
+        AccountMeta::new(TAKER, true),
+        AccountMeta::new(MAKER, false),
+        AccountMeta::new(basket, false),
+        AccountMeta::new_readonly(system_program, false),
+        // Requested leg
+        AccountMeta::new_readonly(MINT_B, false),
+        AccountMeta::new(taker_ata_b, false),
+        AccountMeta::new(maker_ata_b, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+    let mut tx_accounts = vec![
+        (TAKER, system_account(LAMPORTS_PER_SOL)),
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (basket, basket_account),
+        (system_program, system_program_account),
+        (MINT_B, mint_account(mollusk, &MAKER, 6)),
+        (taker_ata_b, token_account(mollusk, &MINT_B, &TAKER, RECEIVE_AMOUNT)),
+        (maker_ata_b, token_account(mollusk, &MINT_B, &MAKER, 0)),
+        (token_program, token_program_account),
+    ];
+
+    for mint in BASKET_OFFERED {
+        let vault = associated_token_address(&basket, &mint);
+        let taker_ata = Pubkey::new_unique();
+
+        ix_accounts.extend([
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(taker_ata, false),
+            AccountMeta::new_readonly(token_program, false),
+        ]);
+        tx_accounts.extend([
+            (mint, mint_account(mollusk, &MAKER, 6)),
+            (vault, token_account(mollusk, &mint, &basket, OFFER_AMOUNT)),
+            (taker_ata, token_account(mollusk, &mint, &TAKER, 0)),
+        ]);
+    }
+
+    let ix_data = TakeBasketIxData { taker: *TAKER.as_array() };
+
+    // Ix discriminator = 7
+    let mut ser_ix_data = vec![7];
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    (instruction, tx_accounts)
+}
+
+/// RefundBasket handing the `basket_account` legs back to `MAKER`.
+pub fn refund_basket(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (basket, basket_account) = basket_account(mollusk);
+
+    let mut ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new(basket, false),
+        AccountMeta::new_readonly(system_program, false),
+    ];
+    let mut tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (basket, basket_account),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    for mint in BASKET_OFFERED {
+        let vault = associated_token_address(&basket, &mint);
+        let maker_ata = Pubkey::new_unique();
+
+        ix_accounts.extend([
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(maker_ata, false),
+            AccountMeta::new_readonly(token_program, false),
+        ]);
+        tx_accounts.extend([
+            (mint, mint_account(mollusk, &MAKER, 6)),
+            (vault, token_account(mollusk, &mint, &basket, OFFER_AMOUNT)),
+            (maker_ata, token_account(mollusk, &mint, &MAKER, 0)),
+        ]);
+    }
+
+    // Ix discriminator = 8
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[8], ix_accounts);
+
+    (instruction, tx_accounts)
+}
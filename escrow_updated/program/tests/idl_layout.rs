@@ -0,0 +1,171 @@
+use core::mem::{offset_of, size_of};
+
+use serde_json::Value;
+
+use escrow_updated::instruction::{
+    InitializeConfigIxData, MakeBasketIxData, MakeEscrowIxData, TakeBasketIxData,
+    TakeEscrowIxData, UpdateConfigIxData,
+};
+use escrow_updated::state::{BasketEscrow, EscrowConfig, EscrowState, Leg, Offer, OfferBook};
+
+const IDL: &str = include_str!("../../client/idl/escrow_updated.json");
+
+fn idl() -> Value {
+    serde_json::from_str(IDL).unwrap()
+}
+
+fn idl_type<'a>(idl: &'a Value, name: &str) -> &'a Value {
+    idl["types"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .chain(idl["accounts"].as_array().unwrap())
+        .find(|ty| ty["name"] == name)
+        .map(|ty| &ty["type"])
+        .unwrap_or_else(|| panic!("{name} is missing from the IDL"))
+}
+
+/// Bytes the generated client reads and writes for `ty`, beet packs fields back to back.
+fn idl_size(idl: &Value, ty: &Value) -> usize {
+    if let Some(array) = ty.get("array") {
+        return idl_size(idl, &array[0]) * array[1].as_u64().unwrap() as usize;
+    }
+    if let Some(defined) = ty.get("defined") {
+        let defined = idl_type(idl, defined.as_str().unwrap());
+        if defined["kind"] == "enum" {
+            return 1;
+        }
+        return idl_fields(idl, defined).iter().map(|(_, _, size)| size).sum();
+    }
+    match ty.as_str().unwrap() {
+        "publicKey" => 32,
+        "u64" | "i64" => 8,
+        "u32" => 4,
+        "u16" => 2,
+        "u8" | "bool" => 1,
+        other => panic!("unsupported IDL type {other}"),
+    }
+}
+
+/// Name, offset and size of each field as the generated client lays them out.
+fn idl_fields(idl: &Value, ty: &Value) -> Vec<(String, usize, usize)> {
+    let mut offset = 0;
+    ty["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| {
+            let size = idl_size(idl, &field["type"]);
+            let entry = (field["name"].as_str().unwrap().to_string(), offset, size);
+            offset += size;
+            entry
+        })
+        .collect()
+}
+
+/// Checks the IDL lists `$ty`'s fields in order, at the offsets `repr(C)` puts them,
+/// and that the client serializes exactly `size_of::<$ty>()` bytes.
+macro_rules! assert_idl_layout {
+    ($ty:ident { $($field:ident: $idl_name:literal),* $(,)? }) => {{
+        let idl = idl();
+        let fields = idl_fields(&idl, idl_type(&idl, stringify!($ty)));
+
+        let expected: Vec<(String, usize)> =
+            vec![$(($idl_name.to_string(), offset_of!($ty, $field))),*];
+        let actual: Vec<(String, usize)> =
+            fields.iter().map(|(name, offset, _)| (name.clone(), *offset)).collect();
+        assert_eq!(actual, expected, "{} fields", stringify!($ty));
+
+        let idl_len: usize = fields.iter().map(|(_, _, size)| size).sum();
+        assert_eq!(idl_len, size_of::<$ty>(), "{} size", stringify!($ty));
+    }};
+}
+
+#[test]
+fn test_ix_data_layout_matches_idl() {
+    assert_idl_layout!(MakeEscrowIxData {
+        maker: "maker",
+        designated_taker: "designatedTaker",
+        seed: "seed",
+        amount: "amount",
+        receive_amount: "receiveAmount",
+        native_side: "nativeSide",
+        seed_len: "seedLen",
+        _padding: "padding",
+    });
+    assert_idl_layout!(TakeEscrowIxData { taker: "taker" });
+    assert_idl_layout!(InitializeConfigIxData {
+        fee_recipient: "feeRecipient",
+        fee_bps: "feeBps",
+    });
+    assert_idl_layout!(UpdateConfigIxData {
+        fee_recipient: "feeRecipient",
+        fee_bps: "feeBps",
+    });
+    assert_idl_layout!(MakeBasketIxData {
+        maker: "maker",
+        offered_amounts: "offeredAmounts",
+        requested: "requested",
+        offered_count: "offeredCount",
+        requested_count: "requestedCount",
+        _padding: "padding",
+    });
+    assert_idl_layout!(TakeBasketIxData { taker: "taker" });
+}
+
+#[test]
+fn test_account_layout_matches_idl() {
+    assert_idl_layout!(EscrowState {
+        maker: "maker",
+        mint_a: "mintA",
+        mint_b: "mintB",
+        designated_taker: "designatedTaker",
+        seed: "seed",
+        amount: "amount",
+        receive_amount: "receiveAmount",
+        native_side: "nativeSide",
+        bump: "bump",
+        sol_vault_bump: "solVaultBump",
+        seed_len: "seedLen",
+        offer_slot: "offerSlot",
+        _padding: "padding",
+    });
+    assert_idl_layout!(EscrowConfig {
+        admin: "admin",
+        fee_recipient: "feeRecipient",
+        fee_bps: "feeBps",
+        bump: "bump",
+        _padding: "padding",
+    });
+    assert_idl_layout!(Offer {
+        escrow: "escrow",
+        maker: "maker",
+        amount: "amount",
+        receive_amount: "receiveAmount",
+        next_free: "nextFree",
+        is_open: "isOpen",
+        _padding: "padding",
+    });
+    assert_idl_layout!(OfferBook {
+        mint_a: "mintA",
+        mint_b: "mintB",
+        open_offers: "openOffers",
+        free_head: "freeHead",
+        bump: "bump",
+        _padding: "padding",
+        offers: "offers",
+    });
+    assert_idl_layout!(Leg {
+        mint: "mint",
+        amount: "amount",
+    });
+    assert_idl_layout!(BasketEscrow {
+        maker: "maker",
+        offered_count: "offeredCount",
+        requested_count: "requestedCount",
+        bump: "bump",
+        _padding: "padding",
+        offered: "offered",
+        requested: "requested",
+    });
+}
@@ -0,0 +1,127 @@
+use pinocchio::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction;
+
+use escrow_updated::error::MyProgramError;
+use escrow_updated::instruction::system_interface::{
+    Allocate, Assign, CreateAccount, CreateAccountWithSeed, Transfer,
+    CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN,
+};
+use escrow_updated::state::create_with_seed;
+
+const LAMPORTS: u64 = 1_461_600;
+
+const SPACE: u64 = 165;
+
+#[test]
+fn test_system_program_id() {
+    assert_eq!(
+        Pubkey::new_from_array(pinocchio_system::ID),
+        solana_sdk::system_program::ID
+    );
+}
+
+#[test]
+fn test_create_account_data() {
+    let owner = Pubkey::new_unique();
+
+    let expected = system_instruction::create_account(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        LAMPORTS,
+        SPACE,
+        &owner,
+    );
+
+    assert_eq!(
+        CreateAccount::instruction_data(LAMPORTS, SPACE, owner.as_array()),
+        expected.data[..]
+    );
+}
+
+#[test]
+fn test_create_account_with_seed_data() {
+    let base = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    // Empty, typical and longest seeds shift every field after the seed
+    for seed in ["", "escrow-1", "0123456789abcdef0123456789abcdef"] {
+        let expected = system_instruction::create_account_with_seed(
+            &Pubkey::new_unique(),
+            &Pubkey::create_with_seed(&base, seed, &owner).unwrap(),
+            &base,
+            seed,
+            LAMPORTS,
+            SPACE,
+            &owner,
+        );
+
+        let mut buffer = [0; CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN];
+        let data = CreateAccountWithSeed::instruction_data(
+            &mut buffer,
+            base.as_array(),
+            seed,
+            LAMPORTS,
+            SPACE,
+            owner.as_array(),
+        )
+        .unwrap();
+
+        assert_eq!(data, &expected.data[..]);
+    }
+}
+
+#[test]
+fn test_create_account_with_seed_too_long() {
+    let mut buffer = [0; CREATE_ACCOUNT_WITH_SEED_MAX_DATA_LEN];
+
+    assert!(CreateAccountWithSeed::instruction_data(
+        &mut buffer,
+        &[1; 32],
+        "0123456789abcdef0123456789abcdef0",
+        LAMPORTS,
+        SPACE,
+        &[2; 32],
+    )
+    .is_err());
+}
+
+#[test]
+fn test_allocate_data() {
+    let expected = system_instruction::allocate(&Pubkey::new_unique(), SPACE);
+
+    assert_eq!(Allocate::instruction_data(SPACE), expected.data[..]);
+}
+
+#[test]
+fn test_assign_data() {
+    let owner = Pubkey::new_unique();
+
+    let expected = system_instruction::assign(&Pubkey::new_unique(), &owner);
+
+    assert_eq!(Assign::instruction_data(owner.as_array()), expected.data[..]);
+}
+
+#[test]
+fn test_transfer_data() {
+    let expected =
+        system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), LAMPORTS);
+
+    assert_eq!(Transfer::instruction_data(LAMPORTS), expected.data[..]);
+}
+
+#[test]
+fn test_create_with_seed_off_chain() {
+    let base = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    // No sha256 syscall on the host, the seeded path errors out instead of panicking
+    assert_eq!(
+        create_with_seed(base.as_array(), b"escrow-1", owner.as_array()),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(
+        create_with_seed(base.as_array(), &[0; 33], owner.as_array()),
+        Err(ProgramError::Custom(MyProgramError::InvalidSeed as u32))
+    );
+}
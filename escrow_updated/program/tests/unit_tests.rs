@@ -1,147 +1,1246 @@
+mod common;
+
+use core::mem::{offset_of, size_of};
+
+use common::*;
 use mollusk_svm::result::{Check, ProgramResult};
-use mollusk_svm::{program, Mollusk};
-use solana_sdk::account::Account;
-use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::instruction::AccountMeta;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use solana_sdk::pubkey;
+use solana_sdk::program_error::ProgramError;
 use solana_sdk::pubkey::Pubkey;
-extern crate alloc;
-use alloc::vec;
 
-use solana_pinocchio_starter::instruction::{InitializeMyStateIxData, UpdateMyStateIxData};
-use solana_pinocchio_starter::state::{to_bytes, DataLen, MyState, State};
-use solana_pinocchio_starter::ID;
-use solana_sdk::rent::Rent;
-use solana_sdk::sysvar::Sysvar;
+use escrow_updated::error::MyProgramError;
+use escrow_updated::instruction::{MakeBasketIxData, MakeEscrowIxData, MAKE_BASKET_LEG_ACCOUNTS};
+use escrow_updated::state::{
+    load_acc_mut_unchecked, load_acc_unchecked, BasketEscrow, DataLen, EscrowConfig, EscrowState,
+    Leg, NativeSide, OfferBook,
+};
+
+#[test]
+fn test_make_escrow_sol_offer() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = make_sol_offer(&mollusk);
+
+    let (escrow_pda, bump) = escrow_pda();
+    let (sol_vault, _) = sol_vault_pda(&escrow_pda);
 
-pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+    let make_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow_pda).owner(&PROGRAM).space(EscrowState::LEN).build(),
+            Check::account(&sol_vault).lamports(OFFER_AMOUNT).build(),
+        ],
+    );
 
-pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+    let escrow_state =
+        unsafe { *load_acc_unchecked::<EscrowState>(&make_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(escrow_state.maker, *MAKER.as_array());
+    assert_eq!(escrow_state.mint_b, *MINT_B.as_array());
+    assert_eq!(escrow_state.amount, OFFER_AMOUNT);
+    assert_eq!(escrow_state.receive_amount, RECEIVE_AMOUNT);
+    assert_eq!(escrow_state.native_side, NativeSide::Offered);
+    assert_eq!(escrow_state.bump, bump);
+    assert_eq!(escrow_state.offer_slot, OfferBook::NIL);
+}
+
+#[test]
+fn test_make_escrow_prefunded() {
+    let mollusk = mollusk();
 
-pub const PAYER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+    let (instruction, mut tx_accounts) = make_sol_offer(&mollusk);
 
-pub fn mollusk() -> Mollusk {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
-    mollusk
+    // Lamports sent to the escrow PDA ahead of time must not block the offer
+    tx_accounts[3].1 = system_account(1);
+
+    let (escrow_pda, _) = escrow_pda();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow_pda)
+                .owner(&PROGRAM)
+                .space(EscrowState::LEN)
+                .lamports(mollusk.sysvars.rent.minimum_balance(EscrowState::LEN))
+                .build(),
+        ],
+    );
 }
 
-pub fn get_rent_data() -> Vec<u8> {
-    let rent = Rent::default();
-    unsafe {
-        core::slice::from_raw_parts(&rent as *const Rent as *const u8, Rent::size_of()).to_vec()
+#[test]
+fn test_make_escrow_token_offer() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = make_token_offer(&mollusk);
+
+    let (escrow_pda, _) = escrow_pda();
+    let vault = associated_token_address(&escrow_pda, &MINT_A);
+
+    let make_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow_pda).owner(&PROGRAM).space(EscrowState::LEN).build(),
+            Check::account(&vault).owner(&TOKEN_PROGRAM).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&make_res.resulting_accounts[4].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&make_res.resulting_accounts[5].1), 0);
+
+    let escrow_state =
+        unsafe { *load_acc_unchecked::<EscrowState>(&make_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(escrow_state.mint_a, *MINT_A.as_array());
+    assert_eq!(escrow_state.amount, OFFER_AMOUNT);
+    assert_eq!(escrow_state.native_side, NativeSide::Requested);
+}
+
+#[test]
+fn test_make_escrow_existing_vault() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = make_token_offer(&mollusk);
+
+    // The vault ATA was already created, by the maker or anyone else
+    let (escrow_pda, _) = escrow_pda();
+    tx_accounts[4].1 = token_account(&mollusk, &MINT_A, &escrow_pda, 0);
+
+    let make_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    assert_eq!(token_amount(&make_res.resulting_accounts[4].1), OFFER_AMOUNT);
+
+    let escrow_state =
+        unsafe { *load_acc_unchecked::<EscrowState>(&make_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(escrow_state.amount, OFFER_AMOUNT);
+}
+
+#[test]
+fn test_make_escrow_unsupported_mint_extension() {
+    let mollusk = mollusk();
+
+    // Non-transferable, permanent delegate and transfer hook
+    let extensions: [(u16, &[u8]); 3] = [(9, &[]), (12, &[1; 32]), (14, &[1; 64])];
+
+    for extension in extensions {
+        let (mut instruction, mut tx_accounts) = make_token_offer(&mollusk);
+
+        tx_accounts[1].1 = token_2022_mint_account(&mollusk, &MAKER, 6, &[extension]);
+        instruction.accounts[8].pubkey = TOKEN_2022_PROGRAM;
+        tx_accounts[8] = token_2022_program_account();
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &tx_accounts,
+            &[Check::err(ProgramError::Custom(MyProgramError::UnsupportedMintExtension as u32))],
+        );
     }
 }
 
 #[test]
-fn test_initialize_mystate() {
+fn test_take_escrow_sol_offer() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, system_account) = program::keyed_account_for_system_program();
+    let (instruction, tx_accounts) = take_sol_offer(&mollusk, MINT_B);
 
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    let maker_lamports = tx_accounts[1].1.lamports;
+    let escrow_rent = tx_accounts[2].1.lamports;
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
-    let mystate_account = Account::new(0, 0, &system_program);
-    let min_balance = mollusk.sysvars.rent.minimum_balance(Rent::size_of());
-    let mut rent_account = Account::new(min_balance, Rent::size_of(), &RENT);
-    rent_account.data = get_rent_data();
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[2].0).lamports(0).build(),
+            Check::account(&tx_accounts[3].0).lamports(0).build(),
+            Check::account(&TAKER).lamports(LAMPORTS_PER_SOL + OFFER_AMOUNT).build(),
+            Check::account(&MAKER).lamports(maker_lamports + escrow_rent).build(),
+        ],
+    );
 
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-        AccountMeta::new_readonly(RENT, false),
-        AccountMeta::new_readonly(system_program, false),
-    ];
+    assert_eq!(token_amount(&take_res.resulting_accounts[7].1), 0);
+    assert_eq!(token_amount(&take_res.resulting_accounts[8].1), RECEIVE_AMOUNT);
+}
+
+#[test]
+fn test_take_escrow_token_offer() {
+    let mollusk = mollusk();
 
-    // Create the instruction data
-    let ix_data = InitializeMyStateIxData {
-        owner: *PAYER.as_array(),
-        data: [1; 32],
-        bump,
-    };
+    let (instruction, tx_accounts) = take_token_offer(&mollusk);
 
-    // Ix discriminator = 0
-    let mut ser_ix_data = vec![0];
+    let escrow = tx_accounts[2].0;
+    let escrow_rent = tx_accounts[2].1.lamports;
+    let vault = tx_accounts[3].0;
+    let vault_rent = tx_accounts[3].1.lamports;
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&TAKER).lamports(LAMPORTS_PER_SOL - RECEIVE_AMOUNT).build(),
+            Check::account(&MAKER)
+                .lamports(LAMPORTS_PER_SOL + RECEIVE_AMOUNT + vault_rent + escrow_rent)
+                .build(),
+            Check::account(&escrow).lamports(0).build(),
+            Check::account(&vault).lamports(0).build(),
+        ],
+    );
 
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), OFFER_AMOUNT);
+}
 
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-        (RENT, rent_account.clone()),
-        (system_program, system_account.clone()),
-    ];
+#[test]
+fn test_take_escrow_vault_with_dust() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_token_offer(&mollusk);
+
+    // Anyone can send tokens to the vault, they must not keep it from closing
+    let vault_authority = tx_accounts[2].0;
+    tx_accounts[3].1 = token_account(&mollusk, &MINT_A, &vault_authority, OFFER_AMOUNT + 1);
+    tx_accounts[13].1 = token_account(&mollusk, &MINT_A, &MAKER, 0);
+
+    let vault = tx_accounts[3].0;
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::success(), Check::account(&vault).lamports(0).build()],
+    );
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&take_res.resulting_accounts[13].1), 1);
+}
+
+#[test]
+fn test_make_and_take_escrow_transfer_fee_mint() {
+    let mollusk = mollusk_token_2022();
+
+    let (instruction, tx_accounts) = make_transfer_fee_offer(&mollusk);
+
+    let (escrow_pda, _) = escrow_pda();
+    let vault = tx_accounts[4].0;
+
+    let make_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    // The escrow holds what landed in the vault, the transfer fee is withheld there
+    let fee = OFFER_AMOUNT * FEE_BPS as u64 / 10_000;
+    let deposited = OFFER_AMOUNT - fee;
+
+    let escrow_account = make_res.get_account(&escrow_pda).unwrap().clone();
+    let escrow_state = unsafe { *load_acc_unchecked::<EscrowState>(&escrow_account.data).unwrap() };
+    assert_eq!(escrow_state.amount, deposited);
+
+    let vault_account = make_res.get_account(&vault).unwrap().clone();
+    assert_eq!(token_amount(&vault_account), deposited);
+    assert_eq!(withheld_amount(&vault_account), fee);
 
-    let init_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+    let (mut instruction, mut tx_accounts) = take_token_offer(&mollusk);
 
-    assert!(init_res.program_result == ProgramResult::Success);
+    tx_accounts[2].1 = escrow_account;
+    instruction.accounts[3].pubkey = vault;
+    tx_accounts[3] = (vault, vault_account);
+    tx_accounts[4].1 = make_res.get_account(&MINT_A).unwrap().clone();
+    tx_accounts[6].1 = token_2022_account(&mollusk, &MINT_A, &TAKER, 0, 0);
+    instruction.accounts[10].pubkey = TOKEN_2022_PROGRAM;
+    tx_accounts.push(token_2022_program_account());
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow_pda).lamports(0).build(),
+            Check::account(&vault).lamports(0).build(),
+        ],
+    );
+
+    // The taker pays the transfer fee on the way out as well
+    let taker_fee = deposited * FEE_BPS as u64 / 10_000;
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), deposited - taker_fee);
 }
 
 #[test]
-fn test_update_mystate() {
+fn test_take_escrow_wrong_vault_owner() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, _system_account) = program::keyed_account_for_system_program();
+    let (instruction, mut tx_accounts) = take_token_offer(&mollusk);
 
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    // A token account of the right mint that the escrow doesn't own
+    tx_accounts[3].1 = token_account(&mollusk, &MINT_A, &TAKER, OFFER_AMOUNT);
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidVaultOwner as u32))],
+    );
+}
 
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
+#[test]
+fn test_take_escrow_wrong_vault_mint() {
+    let mollusk = mollusk();
 
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
+    let (instruction, mut tx_accounts) = take_token_offer(&mollusk);
 
-    let my_state = MyState {
-        is_initialized: 1,
-        owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
-        update_count: 0,
-        bump,
-    };
+    // Owned by the escrow, holding another mint
+    let vault_authority = tx_accounts[2].0;
+    tx_accounts[3].1 = token_account(&mollusk, &MINT_C, &vault_authority, OFFER_AMOUNT);
 
-    mystate_account.data = unsafe { to_bytes(&my_state).to_vec() };
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidVaultMint as u32))],
+    );
+}
 
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-    ];
+#[test]
+fn test_take_escrow_wrong_mint() {
+    let mollusk = mollusk();
 
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
+    // Pays with a mint other than the one the escrow asked for
+    let (instruction, tx_accounts) = take_sol_offer(&mollusk, Pubkey::new_unique());
 
-    // Ix discriminator = 1
-    let mut ser_ix_data = vec![1];
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::MintMismatch as u32))],
+    );
+}
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+#[test]
+fn test_take_escrow_wrong_owner() {
+    let mollusk = mollusk();
 
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-    ];
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // maker_ata_b belongs to the taker instead of the maker
+    tx_accounts[8].1 = token_account(&mollusk, &MINT_B, &TAKER, 0);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidTokenAccountOwner as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_insufficient_balance() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    tx_accounts[7].1 = token_account(&mollusk, &MINT_B, &TAKER, RECEIVE_AMOUNT - 1);
+
+    // spl-token TokenError::InsufficientFunds
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(1))],
+    );
+}
+
+#[test]
+fn test_take_escrow_twice() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    let take_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let retake_res = mollusk.process_instruction(&instruction, &take_res.resulting_accounts);
+
+    assert!(retake_res.program_result != ProgramResult::Success);
+}
+
+#[test]
+fn test_take_escrow_token_offer_with_fee() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_token_offer(&mollusk);
+
+    let config = unsafe { load_acc_mut_unchecked::<EscrowConfig>(&mut tx_accounts[11].1.data).unwrap() };
+    config.fee_bps = FEE_BPS;
+    let fee_recipient = tx_accounts[12].0;
+    tx_accounts[12].1 = system_account(LAMPORTS_PER_SOL);
+
+    let fee = RECEIVE_AMOUNT * FEE_BPS as u64 / EscrowConfig::BPS_DENOMINATOR;
+    let rent = tx_accounts[2].1.lamports + tx_accounts[3].1.lamports;
+
+    // The taker pays the full price, the fee comes out of the maker's side
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&TAKER).lamports(LAMPORTS_PER_SOL - RECEIVE_AMOUNT).build(),
+            Check::account(&MAKER).lamports(LAMPORTS_PER_SOL + RECEIVE_AMOUNT - fee + rent).build(),
+            Check::account(&fee_recipient).lamports(LAMPORTS_PER_SOL + fee).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_take_escrow_sol_offer_with_fee() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // Token fees go to the configured recipient's token account for mint B
+    let fee_wallet = Pubkey::new_unique();
+    let config = unsafe { load_acc_mut_unchecked::<EscrowConfig>(&mut tx_accounts[11].1.data).unwrap() };
+    config.fee_bps = FEE_BPS;
+    config.fee_recipient = *fee_wallet.as_array();
+    tx_accounts[12].1 = token_account(&mollusk, &MINT_B, &fee_wallet, 0);
+
+    let fee = RECEIVE_AMOUNT * FEE_BPS as u64 / EscrowConfig::BPS_DENOMINATOR;
+
+    let take_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[7].1), 0);
+    assert_eq!(token_amount(&take_res.resulting_accounts[8].1), RECEIVE_AMOUNT - fee);
+    assert_eq!(token_amount(&take_res.resulting_accounts[12].1), fee);
+}
+
+#[test]
+fn test_take_escrow_wrong_fee_recipient() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = take_token_offer(&mollusk);
+
+    let config = unsafe { load_acc_mut_unchecked::<EscrowConfig>(&mut tx_accounts[11].1.data).unwrap() };
+    config.fee_bps = FEE_BPS;
+
+    // The taker routes the fee to themselves
+    instruction.accounts[13].pubkey = TAKER;
+    tx_accounts.remove(12);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidFeeRecipient as u32))],
+    );
+}
+
+#[test]
+fn test_initialize_config() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = initialize_config(&mollusk, FEE_BPS);
+
+    let config_pda = tx_accounts[1].0;
+
+    let init_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&config_pda).owner(&PROGRAM).space(EscrowConfig::LEN).build(),
+        ],
+    );
+
+    let config_account = init_res.get_account(&config_pda).unwrap();
+    let config = unsafe { *load_acc_unchecked::<EscrowConfig>(&config_account.data).unwrap() };
+    assert_eq!(config.admin, *MAKER.as_array());
+    assert_eq!(config.fee_recipient, *TAKER.as_array());
+    assert_eq!(config.fee_bps, FEE_BPS);
+}
+
+#[test]
+fn test_initialize_config_not_upgrade_authority() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = initialize_config(&mollusk, FEE_BPS);
+
+    // Someone other than the upgrade authority tries to claim the config first
+    tx_accounts[4] = program_data_account(&mollusk, &TAKER);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::Unauthorized as u32))],
+    );
+}
+
+#[test]
+fn test_initialize_config_fee_too_high() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = initialize_config(&mollusk, EscrowConfig::MAX_FEE_BPS + 1);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::FeeTooHigh as u32))],
+    );
+}
+
+#[test]
+fn test_update_config() {
+    let mollusk = mollusk();
+
+    let fee_recipient = Pubkey::new_unique();
+    let (instruction, tx_accounts) =
+        update_config(&mollusk, &fee_recipient, EscrowConfig::MAX_FEE_BPS);
 
     let update_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let config =
+        unsafe { *load_acc_unchecked::<EscrowConfig>(&update_res.resulting_accounts[1].1.data).unwrap() };
+    assert_eq!(config.admin, *MAKER.as_array());
+    assert_eq!(config.fee_recipient, *fee_recipient.as_array());
+    assert_eq!(config.fee_bps, EscrowConfig::MAX_FEE_BPS);
+}
+
+#[test]
+fn test_update_config_fee_too_high() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) =
+        update_config(&mollusk, &Pubkey::new_unique(), EscrowConfig::MAX_FEE_BPS + 1);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::FeeTooHigh as u32))],
+    );
+}
+
+#[test]
+fn test_update_config_not_admin() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = update_config(&mollusk, &TAKER, FEE_BPS);
+
+    instruction.accounts[0].pubkey = TAKER;
+    tx_accounts[0].0 = TAKER;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::Unauthorized as u32))],
+    );
+}
+
+#[test]
+fn test_make_escrow_maker_not_signer() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = make_sol_offer(&mollusk);
+
+    instruction.accounts[0].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::MissingSigner as u32))],
+    );
+}
+
+#[test]
+fn test_make_escrow_wrong_rent_sysvar() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = make_sol_offer(&mollusk);
+
+    let fake_rent = Pubkey::new_unique();
+    instruction.accounts[6].pubkey = fake_rent;
+    tx_accounts[6] = (fake_rent, rent_account(&mollusk));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidProgramAccount as u32))],
+    );
+}
+
+#[test]
+fn test_make_escrow_wrong_pda() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = make_sol_offer(&mollusk);
+
+    let not_escrow = Pubkey::new_unique();
+    instruction.accounts[3].pubkey = not_escrow;
+    tx_accounts[3].0 = not_escrow;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::PdaMismatch as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_readonly_escrow() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    instruction.accounts[2].is_writable = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::AccountNotWritable as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_foreign_escrow_account() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // Same bytes, but not owned by the escrow program
+    tx_accounts[2].1.owner = Pubkey::new_unique();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidAccountOwner as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_wrong_token_account_mint() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // taker_ata_b holds some other mint
+    tx_accounts[7].1 = token_account(&mollusk, &Pubkey::new_unique(), &TAKER, RECEIVE_AMOUNT);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidTokenAccountMint as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_wrong_token_program() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // Mint B is an SPL Token mint but Token-2022 is passed for it
+    instruction.accounts[11].pubkey = TOKEN_2022_PROGRAM;
+    tx_accounts.push(token_2022_program_account());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidTokenProgram as u32))],
+    );
+}
+
+#[test]
+fn test_initialize_offer_book() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = initialize_offer_book(&mollusk);
+
+    let (offer_book, bump) = offer_book_pda(&Pubkey::default(), &MINT_B);
+
+    let init_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&offer_book).owner(&PROGRAM).space(OfferBook::LEN).build(),
+        ],
+    );
+
+    let book =
+        unsafe { *load_acc_unchecked::<OfferBook>(&init_res.resulting_accounts[1].1.data).unwrap() };
+    assert_eq!(book.mint_b, *MINT_B.as_array());
+    assert_eq!(book.bump, bump);
+    assert_eq!(book.open_offers, 0);
+    assert_eq!(book.free_head, 0);
+    assert_eq!(book.offers[0].next_free, 1);
+    assert_eq!(book.offers[OfferBook::CAPACITY - 1].next_free, OfferBook::NIL);
+}
+
+#[test]
+fn test_make_escrow_listed() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = make_sol_offer(&mollusk);
+
+    let (offer_book, offer_book_account) = offer_book_account(&mollusk, &[]);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, offer_book_account));
+
+    let (escrow_pda, _) = escrow_pda();
+
+    let make_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let escrow_account = make_res.get_account(&escrow_pda).unwrap();
+    let escrow_state = unsafe { *load_acc_unchecked::<EscrowState>(&escrow_account.data).unwrap() };
+    assert_eq!(escrow_state.offer_slot, 0);
+
+    let book_account = make_res.get_account(&offer_book).unwrap();
+    let book = unsafe { *load_acc_unchecked::<OfferBook>(&book_account.data).unwrap() };
+    assert_eq!(book.open_offers, 1);
+    assert_eq!(book.free_head, 1);
+    assert_eq!(book.offers[0].escrow, *escrow_pda.as_array());
+    assert_eq!(book.offers[0].maker, *MAKER.as_array());
+    assert_eq!(book.offers[0].amount, OFFER_AMOUNT);
+    assert_eq!(book.offers[0].is_open, 1);
+}
+
+#[test]
+fn test_make_escrow_offer_book_full() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = make_sol_offer(&mollusk);
+
+    let listed: Vec<Pubkey> = (0..OfferBook::CAPACITY).map(|_| Pubkey::new_unique()).collect();
+    let (offer_book, offer_book_account) = offer_book_account(&mollusk, &listed);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, offer_book_account));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::OfferBookFull as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_delists_and_reuses_slot() {
+    let mollusk = mollusk();
+
+    let (escrow_pda, _) = escrow_pda();
+    let other_escrow = Pubkey::new_unique();
+
+    // The taken escrow sits in slot 1, behind another open offer
+    let (mut instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[2].1.data).unwrap() };
+    escrow_state.offer_slot = 1;
+
+    let (offer_book, offer_book_account) = offer_book_account(&mollusk, &[other_escrow, escrow_pda]);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, offer_book_account));
+
+    let take_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let book_account = take_res.get_account(&offer_book).unwrap().clone();
+    let book = unsafe { *load_acc_unchecked::<OfferBook>(&book_account.data).unwrap() };
+    assert_eq!(book.open_offers, 1);
+    assert_eq!(book.free_head, 1);
+    assert_eq!(book.offers[0].escrow, *other_escrow.as_array());
+    assert_eq!(book.offers[1].is_open, 0);
+    assert_eq!(book.offers[1].next_free, 2);
+
+    // The next offer listed takes the freed slot
+    let (mut instruction, mut tx_accounts) = make_sol_offer(&mollusk);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, book_account));
+
+    let make_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let escrow_account = make_res.get_account(&escrow_pda).unwrap();
+    let escrow_state = unsafe { *load_acc_unchecked::<EscrowState>(&escrow_account.data).unwrap() };
+    assert_eq!(escrow_state.offer_slot, 1);
+
+    let book_account = make_res.get_account(&offer_book).unwrap();
+    let book = unsafe { *load_acc_unchecked::<OfferBook>(&book_account.data).unwrap() };
+    assert_eq!(book.open_offers, 2);
+    assert_eq!(book.free_head, 2);
+    assert_eq!(book.offers[1].escrow, *escrow_pda.as_array());
+}
+
+#[test]
+fn test_take_listed_escrow_without_offer_book() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[2].1.data).unwrap() };
+    escrow_state.offer_slot = 0;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+fn test_refund_escrow_delists() {
+    let mollusk = mollusk();
+
+    let (escrow_pda, _) = escrow_pda();
+
+    let (mut instruction, mut tx_accounts) = refund_escrow(&mollusk, NativeSide::Offered);
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[1].1.data).unwrap() };
+    escrow_state.offer_slot = 0;
+
+    let (offer_book, offer_book_account) = offer_book_account(&mollusk, &[escrow_pda]);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, offer_book_account));
+
+    let escrow_rent = tx_accounts[1].1.lamports;
+    let sol_vault = tx_accounts[2].0;
+
+    let refund_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&MAKER).lamports(LAMPORTS_PER_SOL + OFFER_AMOUNT + escrow_rent).build(),
+            Check::account(&escrow_pda).lamports(0).build(),
+            Check::account(&sol_vault).lamports(0).build(),
+        ],
+    );
+
+    let book_account = refund_res.get_account(&offer_book).unwrap();
+    let book = unsafe { *load_acc_unchecked::<OfferBook>(&book_account.data).unwrap() };
+    assert_eq!(book.open_offers, 0);
+    assert_eq!(book.free_head, 0);
+    assert_eq!(book.offers[0].is_open, 0);
+}
+
+#[test]
+fn test_refund_escrow_vault_with_dust() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = refund_escrow(&mollusk, NativeSide::Requested);
+
+    // The maker gets back whatever was sent to the vault along with the deposit
+    let escrow = tx_accounts[1].0;
+    tx_accounts[2].1 = token_account(&mollusk, &MINT_A, &escrow, OFFER_AMOUNT + 1);
+
+    let vault = tx_accounts[2].0;
+
+    let refund_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow).lamports(0).build(),
+            Check::account(&vault).lamports(0).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&refund_res.resulting_accounts[4].1), OFFER_AMOUNT + 1);
+}
+
+#[test]
+fn test_refund_escrow_not_maker() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = refund_escrow(&mollusk, NativeSide::Offered);
+
+    instruction.accounts[0].pubkey = TAKER;
+    tx_accounts[0].0 = TAKER;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidOwner as u32))],
+    );
+}
+
+#[test]
+fn test_refund_escrow_twice() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = refund_escrow(&mollusk, NativeSide::Offered);
+
+    let refund_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let rerefund_res = mollusk.process_instruction(&instruction, &refund_res.resulting_accounts);
+
+    assert!(rerefund_res.program_result != ProgramResult::Success);
+}
+
+#[test]
+fn test_take_escrow_offer_not_listed() {
+    let mollusk = mollusk();
+
+    let (escrow_pda, _) = escrow_pda();
+
+    // The escrow points at slot 1 but the book lists it in slot 0
+    let (mut instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[2].1.data).unwrap() };
+    escrow_state.offer_slot = 1;
+
+    let (offer_book, offer_book_account) = offer_book_account(&mollusk, &[escrow_pda]);
+    instruction.accounts.push(AccountMeta::new(offer_book, false));
+    tx_accounts.push((offer_book, offer_book_account));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::OfferNotListed as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_designated_taker() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[2].1.data).unwrap() };
+    escrow_state.designated_taker = *TAKER.as_array();
+
+    mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+}
+
+#[test]
+fn test_take_escrow_not_designated_taker() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_sol_offer(&mollusk, MINT_B);
+
+    // The offer is reserved for someone other than the taker
+    let escrow_state =
+        unsafe { load_acc_mut_unchecked::<EscrowState>(&mut tx_accounts[2].1.data).unwrap() };
+    escrow_state.designated_taker = *Pubkey::new_unique().as_array();
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidTaker as u32))],
+    );
+}
+
+#[test]
+fn test_make_escrow_with_seed() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = make_sol_offer_with_seed(&mollusk, ESCROW_SEED);
+
+    let (escrow, _, bump) = escrow_address(ESCROW_SEED);
+    let (sol_vault, _) = sol_vault_pda(&escrow);
+
+    let make_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&escrow).owner(&PROGRAM).space(EscrowState::LEN).build(),
+            Check::account(&sol_vault).lamports(OFFER_AMOUNT).build(),
+        ],
+    );
+
+    let escrow_state =
+        unsafe { *load_acc_unchecked::<EscrowState>(&make_res.resulting_accounts[3].1.data).unwrap() };
+    assert!(escrow_state.is_seeded());
+    assert_eq!(escrow_state.seed().unwrap(), ESCROW_SEED.as_bytes());
+    assert_eq!(escrow_state.bump, bump);
+}
+
+#[test]
+fn test_make_escrow_invalid_seed() {
+    let mollusk = mollusk();
+
+    // Longer than the seed buffer, then not valid UTF-8
+    let corruptions = [
+        (offset_of!(MakeEscrowIxData, seed_len), 33),
+        (offset_of!(MakeEscrowIxData, seed), 0xff),
+    ];
+
+    for (offset, byte) in corruptions {
+        let (mut instruction, tx_accounts) = make_sol_offer_with_seed(&mollusk, ESCROW_SEED);
+
+        instruction.data[1 + offset] = byte;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &tx_accounts,
+            &[Check::err(ProgramError::Custom(MyProgramError::InvalidSeed as u32))],
+        );
+    }
+}
+
+#[test]
+fn test_make_escrow_with_seed_wrong_address() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = make_sol_offer_with_seed(&mollusk, ESCROW_SEED);
+
+    // Escrow account at the address another seed derives
+    let (other_escrow, _, _) = escrow_address("escrow-2");
+    instruction.accounts[3].pubkey = other_escrow;
+    tx_accounts[3].0 = other_escrow;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::SeedAddressMismatch as u32))],
+    );
+}
+
+#[test]
+fn test_take_escrow_with_seed() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = take_token_offer_with_seed(&mollusk, ESCROW_SEED);
+
+    let vault = tx_accounts[3].0;
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&vault).lamports(0).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), OFFER_AMOUNT);
+}
+
+#[test]
+fn test_take_escrow_with_seed_wrong_vault_authority() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = take_token_offer_with_seed(&mollusk, ESCROW_SEED);
+
+    // A seeded escrow can't stand in for its own vault authority
+    instruction.accounts[14].pubkey = tx_accounts[2].0;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::PdaMismatch as u32))],
+    );
+}
+
+#[test]
+fn test_make_basket() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = make_basket(&mollusk);
+
+    let (basket, bump) = basket_pda();
+
+    let make_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&basket).owner(&PROGRAM).space(BasketEscrow::LEN).build(),
+        ],
+    );
+
+    for mint in BASKET_OFFERED {
+        let vault = make_res.get_account(&associated_token_address(&basket, &mint)).unwrap();
+        assert_eq!(token_amount(vault), OFFER_AMOUNT);
+    }
+    assert_eq!(token_amount(&make_res.resulting_accounts[7].1), 0);
+    assert_eq!(token_amount(&make_res.resulting_accounts[10].1), 0);
+
+    let basket_state =
+        unsafe { *load_acc_unchecked::<BasketEscrow>(&make_res.resulting_accounts[1].1.data).unwrap() };
+    assert_eq!(basket_state.maker, *MAKER.as_array());
+    assert_eq!(basket_state.offered_count, BASKET_OFFERED.len() as u8);
+    assert_eq!(basket_state.requested_count, 1);
+    assert_eq!(basket_state.bump, bump);
+    assert_eq!(basket_state.offered[1].mint, *MINT_C.as_array());
+    assert_eq!(basket_state.offered[1].amount, OFFER_AMOUNT);
+    assert_eq!(basket_state.requested[0].mint, *MINT_B.as_array());
+}
+
+#[test]
+fn test_make_basket_invalid_leg_count() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = make_basket(&mollusk);
+
+    instruction.data[1 + offset_of!(MakeBasketIxData, offered_count)] =
+        BasketEscrow::MAX_LEGS as u8 + 1;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidLegCount as u32))],
+    );
+}
+
+#[test]
+fn test_make_basket_duplicate_leg_mint() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = make_basket(&mollusk);
+
+    // Ask for the same requested leg twice
+    let requested = 1 + offset_of!(MakeBasketIxData, requested);
+    let leg_len = size_of::<Leg>();
+    instruction.data.copy_within(requested..requested + leg_len, requested + leg_len);
+    instruction.data[1 + offset_of!(MakeBasketIxData, requested_count)] = 2;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::DuplicateLegMint as u32))],
+    );
+}
+
+#[test]
+fn test_make_basket_missing_leg_accounts() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = make_basket(&mollusk);
+
+    // Two offered legs but only the first leg's accounts
+    instruction.accounts.truncate(instruction.accounts.len() - MAKE_BASKET_LEG_ACCOUNTS);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+fn test_take_basket() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = take_basket(&mollusk);
+
+    let (basket, _) = basket_pda();
+    let basket_rent = tx_accounts[2].1.lamports;
+    let vault_rent = tx_accounts[9].1.lamports + tx_accounts[12].1.lamports;
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&basket).lamports(0).build(),
+            Check::account(&tx_accounts[9].0).lamports(0).build(),
+            Check::account(&tx_accounts[12].0).lamports(0).build(),
+            Check::account(&MAKER).lamports(LAMPORTS_PER_SOL + basket_rent + vault_rent).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[5].1), 0);
+    assert_eq!(token_amount(&take_res.resulting_accounts[6].1), RECEIVE_AMOUNT);
+    assert_eq!(token_amount(&take_res.resulting_accounts[10].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&take_res.resulting_accounts[13].1), OFFER_AMOUNT);
+}
+
+#[test]
+fn test_take_basket_vault_with_dust() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = take_basket(&mollusk);
+
+    // Dust in one leg's vault must not keep the basket from being taken
+    let (basket, _) = basket_pda();
+    tx_accounts[12].1 = token_account(&mollusk, &MINT_C, &basket, OFFER_AMOUNT + 1);
+
+    let take_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::success(), Check::account(&tx_accounts[12].0).lamports(0).build()],
+    );
+
+    assert_eq!(token_amount(&take_res.resulting_accounts[10].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&take_res.resulting_accounts[13].1), OFFER_AMOUNT + 1);
+}
+
+#[test]
+fn test_take_basket_mint_mismatch() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = take_basket(&mollusk);
+
+    // Pays the requested leg with a mint other than the one the basket asked for
+    let other_mint = Pubkey::new_unique();
+    instruction.accounts[4].pubkey = other_mint;
+    tx_accounts[4] = (other_mint, mint_account(&mollusk, &MAKER, 6));
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::MintMismatch as u32))],
+    );
+}
+
+#[test]
+fn test_refund_basket() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = refund_basket(&mollusk);
+
+    let (basket, _) = basket_pda();
+    let basket_rent = tx_accounts[1].1.lamports;
+    let vault_rent = tx_accounts[5].1.lamports + tx_accounts[8].1.lamports;
+
+    let refund_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&basket).lamports(0).build(),
+            Check::account(&tx_accounts[5].0).lamports(0).build(),
+            Check::account(&tx_accounts[8].0).lamports(0).build(),
+            Check::account(&MAKER).lamports(LAMPORTS_PER_SOL + basket_rent + vault_rent).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&refund_res.resulting_accounts[6].1), OFFER_AMOUNT);
+    assert_eq!(token_amount(&refund_res.resulting_accounts[9].1), OFFER_AMOUNT);
+}
+
+#[test]
+fn test_refund_basket_vault_with_dust() {
+    let mollusk = mollusk();
+
+    let (instruction, mut tx_accounts) = refund_basket(&mollusk);
+
+    // Dust in one leg's vault must not keep the maker from refunding
+    let (basket, _) = basket_pda();
+    tx_accounts[5].1 = token_account(&mollusk, &MINT_A, &basket, OFFER_AMOUNT + 1);
+
+    let refund_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::success(), Check::account(&tx_accounts[5].0).lamports(0).build()],
+    );
+
+    assert_eq!(token_amount(&refund_res.resulting_accounts[6].1), OFFER_AMOUNT + 1);
+    assert_eq!(token_amount(&refund_res.resulting_accounts[9].1), OFFER_AMOUNT);
+}
+
+#[test]
+fn test_refund_basket_not_maker() {
+    let mollusk = mollusk();
+
+    let (mut instruction, mut tx_accounts) = refund_basket(&mollusk);
+
+    // Someone other than the maker tries to pull the legs back
+    instruction.accounts[0].pubkey = TAKER;
+    tx_accounts[0] = (TAKER, system_account(LAMPORTS_PER_SOL));
 
-    assert!(update_res.program_result == ProgramResult::Success);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(MyProgramError::InvalidOwner as u32))],
+    );
 }
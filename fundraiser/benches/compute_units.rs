@@ -1,111 +1,51 @@
-use mollusk_svm::{program, Mollusk};
-use mollusk_svm_bencher::MolluskComputeUnitBencher;
-use solana_pinocchio_starter::{
-    instruction::{InitializeMyStateIxData, UpdateMyStateIxData},
-    state::{to_bytes, DataLen, MyState, State},
-    ID,
-};
-use solana_sdk::pubkey;
-use solana_sdk::{
-    account::Account,
-    instruction::{AccountMeta, Instruction},
-    native_token::LAMPORTS_PER_SOL,
-    pubkey::Pubkey,
-};
-
-pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+#[path = "../tests/common/mod.rs"]
+mod common;
 
-pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+use common::*;
+use mollusk_svm_bencher::MolluskComputeUnitBencher;
 
-pub const PAYER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+#[path = "../../test_fixtures/budgets.rs"]
+mod budgets;
+use solana_pinocchio_starter::state::{load_acc_mut, Fundraiser};
 
 fn main() {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
-
-    let (system_program, system_account) = program::keyed_account_for_system_program();
-
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
-
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
-    let mystate_account = Account::new(0, 0, &system_program);
-    let min_balance = mollusk.sysvars.rent.minimum_balance(0);
-    let rent_account = Account::new(min_balance, 0, &RENT);
-
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-        AccountMeta::new_readonly(RENT, false),
-        AccountMeta::new_readonly(system_program, false),
-    ];
-
-    // Create the instruction data
-    let ix_data = InitializeMyStateIxData {
-        owner: *PAYER.as_array(),
-        data: [1; 32],
-        bump,
-    };
-
-    // Ix discriminator = 0
-    let mut ser_ix_data = vec![0];
-
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(to_bytes(&ix_data));
-
-    // Create instruction
-    let instruction0 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-
-    // Create tx_accounts vec
-    let tx_accounts0 = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-        (RENT, rent_account.clone()),
-        (system_program, system_account.clone()),
-    ];
-
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
-
-    let my_state = MyState {
-        is_initialized: true,
-        owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
-        update_count: 0,
-    };
-
-    mystate_account.data = to_bytes(&my_state).to_vec();
-
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-    ];
-
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
-
-    // Ix discriminator = 1
-    let mut ser_ix_data = vec![1];
-
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(to_bytes(&ix_data));
-
-    // Create instruction
-    let instruction1 = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts1 = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
+    let mollusk = mollusk();
+
+    let (initialize_ix, initialize_accounts) = initialize(&mollusk);
+    let (contribute_ix, contribute_accounts) = contribute(&mollusk, 5 * ONE_TOKEN, 0, 0);
+    let (check_ix, check_accounts) = check_contributions(&mollusk, HARD_CAP);
+    let (refund_ix, mut refund_accounts) = refund(&mollusk, 50 * ONE_TOKEN, 5 * ONE_TOKEN);
+    let (close_ix, mut close_accounts) = close_fundraiser(&mollusk, 0, 0);
+
+    // The bench clock sits at TIME_STARTED, start these fundraisers early enough
+    // that they have already ended
+    for accounts in [&mut refund_accounts, &mut close_accounts] {
+        unsafe { load_acc_mut::<Fundraiser>(&mut accounts[2].1.data) }
+            .unwrap()
+            .time_started = TIME_STARTED - (TIME_ENDED - TIME_STARTED);
+    }
+
+    let benches = [
+        ("Initialize", &initialize_ix, &initialize_accounts[..]),
+        ("Contribute", &contribute_ix, &contribute_accounts[..]),
+        ("CheckContributions", &check_ix, &check_accounts[..]),
+        ("Refund", &refund_ix, &refund_accounts[..]),
+        ("CloseFundraiser", &close_ix, &close_accounts[..]),
     ];
 
-    MolluskComputeUnitBencher::new(mollusk)
-        .bench(("InitializeMyState", &instruction0, tx_accounts0))
-        .bench(("UpdateMyState", &instruction1, tx_accounts1))
-        .must_pass(true)
-        .out_dir("benches/")
-        .execute();
+    let mut bencher = MolluskComputeUnitBencher::new(mollusk);
+    for bench in benches {
+        bencher = bencher.bench(bench);
+    }
+    bencher.must_pass(true).out_dir("benches/").execute();
+
+    // Fail the run when an instruction goes over its checked-in budget
+    let mollusk = common::mollusk();
+    let consumed: Vec<(&str, u64)> = benches
+        .iter()
+        .map(|(name, instruction, accounts)| {
+            (*name, mollusk.process_instruction(instruction, accounts).compute_units_consumed)
+        })
+        .collect();
+    budgets::check_budgets(&consumed);
 }
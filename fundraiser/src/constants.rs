@@ -0,0 +1,7 @@
+/// Smallest soft cap a fundraiser can be opened with, in whole tokens of its mint.
+pub const MIN_SOFT_CAP: u64 = 3;
+
+/// Per-wallet caps are a share of the soft cap out of `PERCENTAGE_SCALER`.
+pub const PERCENTAGE_SCALER: u64 = 100;
+
+pub const SECONDS_TO_DAYS: i64 = 86400;
@@ -1,4 +1,4 @@
-use crate::instruction::{self, MyProgramInstrution};
+use crate::instruction::{self, FundraiserProgramInstrution};
 use pinocchio::{
     account_info::AccountInfo, no_allocator, nostd_panic_handler, program_entrypoint,
     program_error::ProgramError, pubkey::Pubkey, ProgramResult,
@@ -22,14 +22,26 @@ fn process_instruction(
         .split_first()
         .ok_or(ProgramError::InvalidInstructionData)?;
 
-    match MyProgramInstrution::try_from(ix_disc)? {
-        MyProgramInstrution::InitializeState => {
+    match FundraiserProgramInstrution::try_from(ix_disc)? {
+        FundraiserProgramInstrution::Initialize => {
             log!("Ix:0");
-            instruction::process_initilaize_state(accounts, instruction_data)
+            instruction::process_initilaize(accounts, instruction_data)
         }
-        MyProgramInstrution::UpdateState => {
+        FundraiserProgramInstrution::Contribute => {
             log!("Ix:1");
-            instruction::process_update_state(accounts, instruction_data)
+            instruction::process_contribute(accounts, instruction_data)
+        }
+        FundraiserProgramInstrution::CheckContributions => {
+            log!("Ix:2");
+            instruction::process_check_contributions(accounts)
+        }
+        FundraiserProgramInstrution::Refund => {
+            log!("Ix:3");
+            instruction::process_refund(accounts)
+        }
+        FundraiserProgramInstrution::CloseFundraiser => {
+            log!("Ix:4");
+            instruction::process_close_fundraiser(accounts)
         }
     }
 }
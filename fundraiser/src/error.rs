@@ -10,6 +10,30 @@ pub enum MyProgramError {
     PdaMismatch,
     // Invalid Owner
     InvalidOwner,
+    // Token account or mint doesn't match the fundraiser
+    InvalidMint,
+    // Soft cap is below MIN_SOFT_CAP
+    SoftCapTooLow,
+    // Contributor's running total is below the fundraiser's minimum
+    ContributionTooSmall,
+    // Contributor's running total would go over the fundraiser's per-wallet cap
+    ContributionTooBig,
+    // Fundraiser duration is over
+    FundraiserEnded,
+    // Fundraiser duration isn't over yet
+    FundraiserNotEnded,
+    // Fundraiser raised less than the soft cap
+    TargetNotMet,
+    // Fundraiser reached the soft cap, contributions can't be refunded
+    TargetMet,
+    // Per-wallet cap isn't a share of the soft cap or sits below the minimum
+    InvalidContributionLimits,
+    // Hard cap sits below the soft cap
+    InvalidCaps,
+    // Fundraiser already holds its hard cap
+    HardCapReached,
+    // Contributions still have to be refunded before the fundraiser can close
+    RefundsPending,
 }
 
 impl From<MyProgramError> for ProgramError {
@@ -0,0 +1,120 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_token::{
+    instructions::{CloseAccount, TransferChecked},
+    state::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{close_account, load_acc},
+        Fundraiser,
+    },
+};
+
+/// Hands the vault to the maker and closes the fundraiser, once it ended above the soft
+/// cap or, before the deadline, once it reached the hard cap.
+pub fn process_check_contributions(accounts: &[AccountInfo]) -> ProgramResult {
+    let [
+        maker,
+        mint_to_raise,
+        fundraiser,
+        vault,
+        maker_ata,
+        _system_program,
+        _token_program,
+        _rest @ ..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !fundraiser.is_owned_by(&crate::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let fundraiser_state = unsafe { *load_acc::<Fundraiser>(&fundraiser.try_borrow_data()?)? };
+
+    if fundraiser_state.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+    if fundraiser_state.mint_to_raise.ne(mint_to_raise.key()) {
+        return Err(MyProgramError::InvalidMint.into());
+    }
+
+    // Closing early is only allowed once there's no room left to contribute
+    if !fundraiser_state.hard_cap_reached()
+        && !fundraiser_state.has_ended(Clock::get()?.unix_timestamp)
+    {
+        return Err(MyProgramError::FundraiserNotEnded.into());
+    }
+
+    let vault_amount = {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(fundraiser.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let maker_ata_acc = TokenAccount::from_account_info(maker_ata)?;
+        if maker_ata_acc.owner().ne(maker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if maker_ata_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        vault_acc.amount()
+    };
+
+    // Tokens sent straight to the vault don't count towards the goal
+    if fundraiser_state.current_amount < fundraiser_state.soft_cap {
+        return Err(MyProgramError::TargetNotMet.into());
+    }
+
+    let decimals = Mint::from_account_info(mint_to_raise)?.decimals();
+
+    let bump_seed = [fundraiser_state.bump];
+
+    // Signer seeds
+    let fundraiser_seeds = [
+        Seed::from(Fundraiser::SEED.as_bytes()),
+        Seed::from(maker.key()),
+        Seed::from(&bump_seed[..]),
+    ];
+    let signers = [Signer::from(&fundraiser_seeds[..])];
+
+    TransferChecked {
+        from: vault,
+        to: maker_ata,
+        mint: mint_to_raise,
+        authority: fundraiser,
+        amount: vault_amount,
+        decimals,
+    }
+    .invoke_signed(&signers)?;
+
+    CloseAccount {
+        account: vault,
+        destination: maker,
+        authority: fundraiser,
+    }
+    .invoke_signed(&signers)?;
+
+    // Close the fundraiser account, rent goes back to the maker
+    close_account(fundraiser, maker)?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_token::{
+    instructions::{CloseAccount, TransferChecked},
+    state::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{close_account, load_acc},
+        Fundraiser,
+    },
+};
+
+/// Closes the vault and the fundraiser once it ended and every contribution was refunded,
+/// so the maker gets the rent back and can start a new fundraiser.
+pub fn process_close_fundraiser(accounts: &[AccountInfo]) -> ProgramResult {
+    let [
+        maker,
+        mint_to_raise,
+        fundraiser,
+        vault,
+        maker_ata,
+        _token_program,
+        _rest @ ..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !fundraiser.is_owned_by(&crate::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let fundraiser_state = unsafe { *load_acc::<Fundraiser>(&fundraiser.try_borrow_data()?)? };
+
+    if fundraiser_state.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+    if fundraiser_state.mint_to_raise.ne(mint_to_raise.key()) {
+        return Err(MyProgramError::InvalidMint.into());
+    }
+
+    if !fundraiser_state.has_ended(Clock::get()?.unix_timestamp) {
+        return Err(MyProgramError::FundraiserNotEnded.into());
+    }
+
+    // Contributors still on record have to be refunded first
+    if fundraiser_state.current_amount != 0 {
+        return Err(MyProgramError::RefundsPending.into());
+    }
+
+    let vault_amount = {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(fundraiser.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let maker_ata_acc = TokenAccount::from_account_info(maker_ata)?;
+        if maker_ata_acc.owner().ne(maker.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if maker_ata_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        vault_acc.amount()
+    };
+
+    let bump_seed = [fundraiser_state.bump];
+
+    // Signer seeds
+    let fundraiser_seeds = [
+        Seed::from(Fundraiser::SEED.as_bytes()),
+        Seed::from(maker.key()),
+        Seed::from(&bump_seed[..]),
+    ];
+    let signers = [Signer::from(&fundraiser_seeds[..])];
+
+    // Only tokens sent straight to the vault can be left, the token program won't
+    // close an account that still holds any
+    if vault_amount > 0 {
+        let decimals = Mint::from_account_info(mint_to_raise)?.decimals();
+
+        TransferChecked {
+            from: vault,
+            to: maker_ata,
+            mint: mint_to_raise,
+            authority: fundraiser,
+            amount: vault_amount,
+            decimals,
+        }
+        .invoke_signed(&signers)?;
+    }
+
+    CloseAccount {
+        account: vault,
+        destination: maker,
+        authority: fundraiser,
+    }
+    .invoke_signed(&signers)?;
+
+    // Close the fundraiser account, rent goes back to the maker
+    close_account(fundraiser, maker)?;
+
+    Ok(())
+}
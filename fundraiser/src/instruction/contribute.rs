@@ -1,14 +1,30 @@
-use pinocchio_token::{instructions::TransferChecked, state::{Mint, TokenAccount}};
-
-use crate::utils::{DataLen, load_ix_data, load_account_mut, load_account_mut_unchecked};
-use crate::state::{Contributor, Fundraiser};
-use crate::constants::{MAX_CONTRIBUTION_PERCENTAGE, PERCENTAGE_SCALER, SECONDS_TO_DAYS};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::CreateAccount;
+
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Contributor, Fundraiser,
+    },
+};
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ContributeIxData {
     pub amount: u64,
-    pub fundraiser_bump: u8,
     pub contributor_bump: u8,
 }
 
@@ -25,8 +41,8 @@ pub fn process_contribute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
         contributor_ata,
         vault,
         sysvar_rent_acc,
-        token_program,
-        system_program,
+        _token_program,
+        _system_program,
         _rest @ ..
     ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -36,85 +52,111 @@ pub fn process_contribute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let vault_acc = TokenAccount::from_account_info(vault)?;
+    if !fundraiser.is_owned_by(&crate::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
 
-    assert_eq!(vault_acc.owner(), fundraiser.key());
-    assert_eq!(vault_acc.mint, mint_to_raise);
+    let ix_data = unsafe { load_ix_data::<ContributeIxData>(data)? };
 
-    let contributor_ata_acc = TokenAccount::from_account_info(contributor_ata)?;
-    assert_eq!(contributor_ata_acc.owner(), contributor.key());
+    let fundraiser_state =
+        unsafe { load_acc_mut::<Fundraiser>(fundraiser.borrow_mut_data_unchecked())? };
 
-    let ix_data = unsafe { load_ix_data::<ContributeIxData>(data)? };
+    if fundraiser_state.mint_to_raise.ne(mint_to_raise.key()) {
+        return Err(MyProgramError::InvalidMint.into());
+    }
+
+    if fundraiser_state.has_ended(Clock::get()?.unix_timestamp) {
+        return Err(MyProgramError::FundraiserEnded.into());
+    }
+
+    {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(fundraiser.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let contributor_ata_acc = TokenAccount::from_account_info(contributor_ata)?;
+        if contributor_ata_acc.owner().ne(contributor.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if contributor_ata_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+    }
+
+    if ix_data.amount == 0 {
+        return Err(MyProgramError::ContributionTooSmall.into());
+    }
 
-    if contributor_acc.data_is_empty() || unsafe {contributor_acc.owner() != &crate::ID}{
+    let decimals = Mint::from_account_info(mint_to_raise)?.decimals();
+
+    Contributor::validate_pda(
+        ix_data.contributor_bump,
+        contributor_acc.key(),
+        fundraiser.key(),
+        contributor.key(),
+    )?;
+
+    // First contribution from this wallet opens its Contributor account
+    if contributor_acc.data_is_empty() {
         let rent = Rent::from_account_info(sysvar_rent_acc)?;
 
         let pda_bump_bytes = [ix_data.contributor_bump];
 
+        // Signer seeds
         let contributor_seeds = [
             Seed::from(Contributor::SEED.as_bytes()),
-            Seed::from(fundraiser.key().as_ref()),
-            Seed::from(contributor.key().as_ref()),
+            Seed::from(fundraiser.key()),
+            Seed::from(contributor.key()),
             Seed::from(&pda_bump_bytes[..]),
         ];
-
         let contributor_signer = Signer::from(&contributor_seeds[..]);
 
-        (CreateAccount {
+        CreateAccount {
             from: contributor,
             to: contributor_acc,
             space: Contributor::LEN as u64,
-            lamports: rent.minimum_balance(Contributor::LEN as u64),
             owner: &crate::ID,
-        }).invoke_signed(&[contributor_signer])?;
-
-        let mut contributor_state = (unsafe {
-            load_account_mut_unchecked::<Contributor>(contributor_acc.borrow_mut_data_unchecked())
-        })?;
-
-        contributor_state.initialize(
-            ix_data.amount,
-        );
-
-        let fundraiser_state = (unsafe {
-            load_account_mut_unchecked::<Fundraiser>(fundraiser.borrow_mut_data_unchecked())
-        })?;
-
-        let mint_state = Mint::from_account_info(mint_to_raise)?;
-
-        if ix_data.amount < (10_u32.pow(mint_state.decimals as u32) as u64) {
-            return Err(ProgramError::InvalidAccountData);
+            lamports: rent.minimum_balance(Contributor::LEN),
         }
+        .invoke_signed(&[contributor_signer])?;
 
-        if ix_data.amount > (fundraiser_state.amount_raised * MAX_CONTRIBUTION_PERCENTAGE / PERCENTAGE_SCALER) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let current_timestamp = Clock::get()?.unix_timestamp;
-
-        if fundraiser_state.duration < ((current_timestamp - fundraiser_state.start_timestamp) / SECONDS_TO_DAYS) as u8 {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        Contributor::initialize(contributor_acc, ix_data.contributor_bump)?;
+    }
 
-        let mint_authority = mint_state.mint_authority.ok_or(ProgramError::InvalidAccountData)?;
+    let contributor_state =
+        unsafe { load_acc_mut::<Contributor>(contributor_acc.borrow_mut_data_unchecked())? };
 
-        (
-            TransferChecked {
-                from: contributor_ata,
-                to: vault,
-                mint: mint_to_raise,
-                authority: contributor,
-                amount: ix_data.amount,
-                decimals: mint_state.decimals(),
-            }
-        ).invoke()?;
+    // Anything over the hard cap stays with the contributor
+    let accepted = fundraiser_state.accepted_amount(ix_data.amount)?;
+    let fills_hard_cap = fundraiser_state.current_amount + accepted == fundraiser_state.hard_cap;
 
-        contributor_state.amount += ix_data.amount;
+    // Limits apply to everything this wallet has put in, not just this contribution
+    let contributed = contributor_state
+        .amount
+        .checked_add(accepted)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
 
-        fundraiser_state.current_amount_raised += ix_data.amount;
+    fundraiser_state.check_contribution(contributed, fills_hard_cap)?;
 
-        Ok(())
+    TransferChecked {
+        from: contributor_ata,
+        to: vault,
+        mint: mint_to_raise,
+        authority: contributor,
+        amount: accepted,
+        decimals,
     }
+    .invoke()?;
 
-}
+    contributor_state.amount = contributed;
+    fundraiser_state.current_amount = fundraiser_state
+        .current_amount
+        .checked_add(accepted)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
 
+    Ok(())
+}
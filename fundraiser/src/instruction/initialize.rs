@@ -3,15 +3,17 @@ use pinocchio::{
     instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvars::rent::Rent,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
 
 use pinocchio_system::instructions::CreateAccount;
 
-use pinocchio_token::from_account_info;
+use pinocchio_token::state::{Mint, TokenAccount};
 
 use crate::{
+    constants::{MIN_SOFT_CAP, PERCENTAGE_SCALER},
+    error::MyProgramError,
     state::{
         utils::{load_ix_data, DataLen},
         Fundraiser,
@@ -22,7 +24,16 @@ use crate::{
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct InitializeIxData {
     pub maker: Pubkey,
+    /// Success threshold in base units of the mint.
+    pub soft_cap: u64,
+    /// Most the fundraiser will take in, in base units of the mint.
+    pub hard_cap: u64,
+    /// Smallest running total a wallet can contribute, in whole tokens.
+    pub min_contribution: u64,
+    /// Days the fundraiser takes contributions for.
     pub duration: u8,
+    /// Largest running total a wallet can contribute, as a percentage of the soft cap.
+    pub max_contribution_percentage: u8,
     pub bump: u8,
 }
 
@@ -52,21 +63,69 @@ pub fn process_initilaize(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
         return Err(ProgramError::AccountAlreadyInitialized);
     }
 
-    let vault_acc = TokenAccount::from_account_info(vault)?;
+    let ix_data = unsafe { load_ix_data::<InitializeIxData>(data)? };
+
+    if ix_data.maker.ne(maker.key()) {
+        return Err(MyProgramError::InvalidOwner.into());
+    }
+
+    // A zero-day fundraiser would be over before anyone could contribute
+    if ix_data.duration == 0 {
+        return Err(MyProgramError::InvalidInstructionData.into());
+    }
 
-    assert_eq!(vault_acc.owner(), fundraiser.key());
-    assert_eq!(vault_acc.mint, mint_to_raise);
+    // The vault is the fundraiser PDA's token account for the mint, created by the client
+    {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(fundraiser.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+    }
+
+    let decimals = Mint::from_account_info(mint_to_raise)?.decimals();
+
+    let one_token = 10_u64.pow(decimals as u32);
+
+    if ix_data.soft_cap < MIN_SOFT_CAP * one_token {
+        return Err(MyProgramError::SoftCapTooLow.into());
+    }
+
+    if ix_data.hard_cap < ix_data.soft_cap {
+        return Err(MyProgramError::InvalidCaps.into());
+    }
+
+    if ix_data.max_contribution_percentage == 0
+        || ix_data.max_contribution_percentage as u64 > PERCENTAGE_SCALER
+    {
+        return Err(MyProgramError::InvalidContributionLimits.into());
+    }
+
+    // Both limits are kept in base units so contribute doesn't need the mint decimals for them
+    let min_contribution = ix_data
+        .min_contribution
+        .checked_mul(one_token)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let max_contribution = (ix_data.soft_cap as u128
+        * ix_data.max_contribution_percentage as u128
+        / PERCENTAGE_SCALER as u128) as u64;
+
+    if min_contribution == 0 || min_contribution > max_contribution {
+        return Err(MyProgramError::InvalidContributionLimits.into());
+    }
 
     let rent = Rent::from_account_info(sysvar_rent_acc)?;
 
-    let ix_data = unsafe { load_ix_data::<InitializeIxData>(data)? };
+    Fundraiser::validate_pda(ix_data.bump, fundraiser.key(), maker.key())?;
 
     let bump_seed = [ix_data.bump];
 
     // Signer seeds
     let fundraiser_seeds = [
         Seed::from(Fundraiser::SEED.as_bytes()),
-        Seed::from(maker.key().as_ref()),
+        Seed::from(maker.key()),
         Seed::from(&bump_seed[..]),
     ];
     let fundraiser_signer = Signer::from(&fundraiser_seeds[..]);
@@ -80,16 +139,12 @@ pub fn process_initilaize(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
     }
     .invoke_signed(&[fundraiser_signer])?;
 
-    let mut fundraiser_state = (unsafe{
-        load_account_unchecked::<Fundraiser>(fundraiser.borrow_mut_data_unchecked())
-    })?;
-
-    fundraiser_state.initialize(
-        *maker.key,
-        *mint_to_raise,
-        ix_data.amount,
-        ix_data.duration,
-        ix_data.bump,
+    Fundraiser::initialize(
+        fundraiser,
+        ix_data,
+        mint_to_raise.key(),
+        min_contribution,
+        max_contribution,
         Clock::get()?.unix_timestamp,
     )?;
 
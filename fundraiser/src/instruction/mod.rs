@@ -1,28 +1,36 @@
 use pinocchio::program_error::ProgramError;
 
-pub mod initialize_mystate;
-pub mod update_mystate;
-pub mod deposit;
-pub mod withdraw;
+pub mod check_contributions;
+pub mod close_fundraiser;
+pub mod contribute;
+pub mod initialize;
+pub mod refund;
 
-pub use initialize_mystate::*;
-pub use update_mystate::*;
-pub use deposit::*;
-pub use withdraw::*;
+pub use check_contributions::*;
+pub use close_fundraiser::*;
+pub use contribute::*;
+pub use initialize::*;
+pub use refund::*;
 
 #[repr(u8)]
-pub enum MyProgramInstrution {
-    InitializeState,
-    UpdateState,
+pub enum FundraiserProgramInstrution {
+    Initialize,
+    Contribute,
+    CheckContributions,
+    Refund,
+    CloseFundraiser,
 }
 
-impl TryFrom<&u8> for MyProgramInstrution {
+impl TryFrom<&u8> for FundraiserProgramInstrution {
     type Error = ProgramError;
 
     fn try_from(value: &u8) -> Result<Self, Self::Error> {
         match *value {
-            0 => Ok(MyProgramInstrution::InitializeState),
-            1 => Ok(MyProgramInstrution::UpdateState),
+            0 => Ok(FundraiserProgramInstrution::Initialize),
+            1 => Ok(FundraiserProgramInstrution::Contribute),
+            2 => Ok(FundraiserProgramInstrution::CheckContributions),
+            3 => Ok(FundraiserProgramInstrution::Refund),
+            4 => Ok(FundraiserProgramInstrution::CloseFundraiser),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -0,0 +1,121 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::MyProgramError,
+    state::{
+        utils::{close_account, load_acc, load_acc_mut},
+        Contributor, Fundraiser,
+    },
+};
+
+/// Gives a contributor their tokens back once the fundraiser ended short of its goal.
+pub fn process_refund(accounts: &[AccountInfo]) -> ProgramResult {
+    let [
+        contributor,
+        mint_to_raise,
+        fundraiser,
+        contributor_acc,
+        contributor_ata,
+        vault,
+        _token_program,
+        _rest @ ..
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !contributor.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !fundraiser.is_owned_by(&crate::ID) || !contributor_acc.is_owned_by(&crate::ID) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let fundraiser_state =
+        unsafe { load_acc_mut::<Fundraiser>(fundraiser.borrow_mut_data_unchecked())? };
+
+    if fundraiser_state.mint_to_raise.ne(mint_to_raise.key()) {
+        return Err(MyProgramError::InvalidMint.into());
+    }
+
+    if !fundraiser_state.has_ended(Clock::get()?.unix_timestamp) {
+        return Err(MyProgramError::FundraiserNotEnded.into());
+    }
+
+    // Refunds only open up when the raise failed
+    if fundraiser_state.current_amount >= fundraiser_state.soft_cap {
+        return Err(MyProgramError::TargetMet.into());
+    }
+
+    let contributor_state =
+        unsafe { *load_acc::<Contributor>(&contributor_acc.try_borrow_data()?)? };
+
+    // The Contributor PDA ties the refund to this wallet and fundraiser
+    Contributor::validate_pda(
+        contributor_state.bump,
+        contributor_acc.key(),
+        fundraiser.key(),
+        contributor.key(),
+    )?;
+
+    {
+        let vault_acc = TokenAccount::from_account_info(vault)?;
+        if vault_acc.owner().ne(fundraiser.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if vault_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+
+        let contributor_ata_acc = TokenAccount::from_account_info(contributor_ata)?;
+        if contributor_ata_acc.owner().ne(contributor.key()) {
+            return Err(MyProgramError::InvalidOwner.into());
+        }
+        if contributor_ata_acc.mint().ne(mint_to_raise.key()) {
+            return Err(MyProgramError::InvalidMint.into());
+        }
+    }
+
+    let decimals = Mint::from_account_info(mint_to_raise)?.decimals();
+
+    let bump_seed = [fundraiser_state.bump];
+
+    // Signer seeds
+    let fundraiser_seeds = [
+        Seed::from(Fundraiser::SEED.as_bytes()),
+        Seed::from(&fundraiser_state.maker),
+        Seed::from(&bump_seed[..]),
+    ];
+    let signers = [Signer::from(&fundraiser_seeds[..])];
+
+    TransferChecked {
+        from: vault,
+        to: contributor_ata,
+        mint: mint_to_raise,
+        authority: fundraiser,
+        amount: contributor_state.amount,
+        decimals,
+    }
+    .invoke_signed(&signers)?;
+
+    fundraiser_state.current_amount = fundraiser_state
+        .current_amount
+        .checked_sub(contributor_state.amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Close the Contributor account, rent goes back to the contributor
+    close_account(contributor_acc, contributor)?;
+
+    Ok(())
+}
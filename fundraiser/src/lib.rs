@@ -6,6 +6,7 @@ mod entrypoint;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod constants;
 pub mod error;
 pub mod instruction;
 pub mod state;
@@ -6,12 +6,15 @@ use pinocchio::{
     ProgramResult,
 };
 
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub enum Contributor {
-    is_initialized: bool,
-    amount: u64,
-    bump: u8,
+use crate::error::MyProgramError;
+
+/// Running total of one wallet's contributions to one fundraiser.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contributor {
+    pub amount: u64,
+    pub bump: u8,
+    pub is_initialized: bool,
 }
 
 impl DataLen for Contributor {
@@ -27,16 +30,28 @@ impl Initialized for Contributor {
 impl Contributor {
     pub const SEED: &'static str = "contributor";
 
-    pub fn initialize(
-        amount: u64,
+    pub fn validate_pda(
         bump: u8,
-    ) -> ProgramResult {
-        let contributor =
-            unsafe { load_acc_mut_unchecked::<Contributor>(self.borrow_mut_data_unchecked()) }?;
+        pda: &Pubkey,
+        fundraiser: &Pubkey,
+        contributor: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), fundraiser, contributor, &[bump]];
+        let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
+        if derived != *pda {
+            return Err(MyProgramError::PdaMismatch.into());
+        }
+        Ok(())
+    }
 
-        contributor.is_initialized = true;
-        contributor.amount = amount;
+    pub fn initialize(contributor_acc: &AccountInfo, bump: u8) -> ProgramResult {
+        let contributor = unsafe {
+            load_acc_mut_unchecked::<Contributor>(contributor_acc.borrow_mut_data_unchecked())
+        }?;
+
+        contributor.amount = 0;
         contributor.bump = bump;
+        contributor.is_initialized = true;
 
         Ok(())
     }
@@ -6,17 +6,27 @@ use pinocchio::{
     ProgramResult,
 };
 
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub enum Fundraiser {
-    is_initialized: bool,
-    maker: Pubkey,
-    mint_to_raise: Pubkey,
-    amount_to_raise: u8,
-    current_amount: u8,
-    time_started: i64,
-    duration: u8,
-    bump: u8,
+use crate::{constants::SECONDS_TO_DAYS, error::MyProgramError, instruction::InitializeIxData};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fundraiser {
+    pub maker: Pubkey,
+    pub mint_to_raise: Pubkey,
+    /// Amount the fundraiser has to reach to succeed, in base units.
+    pub soft_cap: u64,
+    /// Amount past which contributions are turned away, in base units.
+    pub hard_cap: u64,
+    pub current_amount: u64,
+    /// Smallest running total a wallet can contribute, in base units.
+    pub min_contribution: u64,
+    /// Largest running total a wallet can contribute, in base units.
+    pub max_contribution: u64,
+    pub time_started: i64,
+    /// Days the fundraiser takes contributions for, counted from `time_started`.
+    pub duration: u8,
+    pub bump: u8,
+    pub is_initialized: bool,
 }
 
 impl DataLen for Fundraiser {
@@ -32,35 +42,70 @@ impl Initialized for Fundraiser {
 impl Fundraiser {
     pub const SEED: &'static str = "fundraiser";
 
-    pub fn validate_pda(bump: u8, pda: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
-        let seed_with_bump = &[Self::SEED.as_bytes(), owner, &[bump]];
+    pub fn validate_pda(bump: u8, pda: &Pubkey, maker: &Pubkey) -> Result<(), ProgramError> {
+        let seed_with_bump = &[Self::SEED.as_bytes(), maker, &[bump]];
         let derived = pubkey::create_program_address(seed_with_bump, &crate::ID)?;
         if derived != *pda {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(MyProgramError::PdaMismatch.into());
         }
         Ok(())
     }
 
     pub fn initialize(
-        maker: Pubkey,
-        mint_to_raise: Pubkey,
-        amount_to_raise: u8,
+        fundraiser_acc: &AccountInfo,
+        ix_data: &InitializeIxData,
+        mint_to_raise: &Pubkey,
+        min_contribution: u64,
+        max_contribution: u64,
         time_started: i64,
-        duration: u8,
-        bump: u8,
     ) -> ProgramResult {
-        let fundraiser =
-            unsafe { load_acc_mut_unchecked::<Fundraiser>(self.borrow_mut_data_unchecked()) }?;
+        let fundraiser = unsafe {
+            load_acc_mut_unchecked::<Fundraiser>(fundraiser_acc.borrow_mut_data_unchecked())
+        }?;
 
-        fundraiser.is_initialized = true;
-        fundraiser.maker = maker;
-        fundraiser.mint_to_raise = mint_to_raise;
-        fundraiser.amount_to_raise = amount_to_raise;
+        fundraiser.maker = ix_data.maker;
+        fundraiser.mint_to_raise = *mint_to_raise;
+        fundraiser.soft_cap = ix_data.soft_cap;
+        fundraiser.hard_cap = ix_data.hard_cap;
         fundraiser.current_amount = 0;
+        fundraiser.min_contribution = min_contribution;
+        fundraiser.max_contribution = max_contribution;
         fundraiser.time_started = time_started;
-        fundraiser.duration = duration;
-        fundraiser.bump = bump;
+        fundraiser.duration = ix_data.duration;
+        fundraiser.bump = ix_data.bump;
+        fundraiser.is_initialized = true;
+
+        Ok(())
+    }
 
+    /// Checks a wallet's running total against the fundraiser's per-wallet limits.
+    ///
+    /// The minimum is waived for the contribution that fills the hard cap, otherwise
+    /// the last few tokens of room could never be raised.
+    pub fn check_contribution(&self, contributed: u64, fills_hard_cap: bool) -> ProgramResult {
+        if contributed < self.min_contribution && !fills_hard_cap {
+            return Err(MyProgramError::ContributionTooSmall.into());
+        }
+        if contributed > self.max_contribution {
+            return Err(MyProgramError::ContributionTooBig.into());
+        }
         Ok(())
     }
+
+    /// Part of `amount` that still fits under the hard cap.
+    pub fn accepted_amount(&self, amount: u64) -> Result<u64, ProgramError> {
+        let room = self.hard_cap.saturating_sub(self.current_amount);
+        if room == 0 {
+            return Err(MyProgramError::HardCapReached.into());
+        }
+        Ok(amount.min(room))
+    }
+
+    pub fn hard_cap_reached(&self) -> bool {
+        self.current_amount >= self.hard_cap
+    }
+
+    pub fn has_ended(&self, now: i64) -> bool {
+        now - self.time_started >= self.duration as i64 * SECONDS_TO_DAYS
+    }
 }
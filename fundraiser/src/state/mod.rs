@@ -1,7 +1,7 @@
-pub mod fundraiser;
 pub mod contributor;
+pub mod fundraiser;
 pub mod utils;
 
-pub use fundraiser::*;
 pub use contributor::*;
+pub use fundraiser::*;
 pub use utils::*;
@@ -1,4 +1,4 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
 use crate::error::MyProgramError;
 
@@ -65,3 +65,19 @@ pub unsafe fn to_bytes<T: DataLen>(data: &T) -> &[u8] {
 pub unsafe fn to_mut_bytes<T: DataLen>(data: &mut T) -> &mut [u8] {
     core::slice::from_raw_parts_mut(data as *mut T as *mut u8, T::LEN)
 }
+
+/// Closes a program-owned account, sending its rent to `destination`.
+pub fn close_account(acc: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    {
+        let mut data = acc.try_borrow_mut_data()?;
+        data.fill(0);
+    }
+
+    let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+    *destination_lamports = destination_lamports
+        .checked_add(acc.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    *acc.try_borrow_mut_lamports()? = 0;
+
+    Ok(())
+}
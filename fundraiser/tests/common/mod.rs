@@ -0,0 +1,378 @@
+#![allow(dead_code)]
+
+#[path = "../../../test_fixtures/token.rs"]
+mod token;
+pub use token::*;
+
+use mollusk_svm::{program, Mollusk};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+
+use solana_pinocchio_starter::instruction::{ContributeIxData, InitializeIxData};
+use solana_pinocchio_starter::state::{to_bytes, Contributor, DataLen, Fundraiser};
+use solana_pinocchio_starter::ID;
+
+pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+
+/// Fundraiser program with the SPL Token program loaded from `tests/elfs`.
+pub fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
+    mollusk.add_program(
+        &TOKEN_PROGRAM,
+        "tests/elfs/spl_token",
+        &mollusk_svm::program::loader_keys::LOADER_V3,
+    );
+    mollusk.sysvars.clock.unix_timestamp = TIME_STARTED;
+    mollusk
+}
+
+pub const MAKER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+
+pub const CONTRIBUTOR: Pubkey = pubkey!("8GRj9cW4cfWCbz4hZ5jRc4ifWJ8VQHk6pbKSx5zKkXgH");
+
+pub const MINT: Pubkey = pubkey!("7sQ4dZxmG3aTqzjfLVQFNqHuJjd1ZGJG6VjC9v6dHt4G");
+
+pub const VAULT: Pubkey = pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+
+pub const DECIMALS: u8 = 6;
+
+/// One whole token of `MINT`.
+pub const ONE_TOKEN: u64 = 1_000_000;
+
+pub const SOFT_CAP: u64 = 100 * ONE_TOKEN;
+
+pub const HARD_CAP: u64 = 150 * ONE_TOKEN;
+
+/// Whole tokens
+pub const MIN_CONTRIBUTION: u64 = 2;
+
+/// Percent of `SOFT_CAP`, 10 whole tokens
+pub const MAX_CONTRIBUTION_PERCENTAGE: u8 = 10;
+
+/// Days
+pub const DURATION: u8 = 7;
+
+pub const TIME_STARTED: i64 = 1_700_000_000;
+
+/// First second after the fundraiser stops taking contributions.
+pub const TIME_ENDED: i64 = TIME_STARTED + DURATION as i64 * 86400;
+
+pub fn fundraiser_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Fundraiser::SEED.as_bytes(), MAKER.as_ref()], &PROGRAM)
+}
+
+pub fn contributor_pda(contributor: &Pubkey) -> (Pubkey, u8) {
+    let (fundraiser_pda, _) = fundraiser_pda();
+    Pubkey::find_program_address(
+        &[
+            Contributor::SEED.as_bytes(),
+            fundraiser_pda.as_ref(),
+            contributor.as_ref(),
+        ],
+        &PROGRAM,
+    )
+}
+
+/// Fundraiser for `SOFT_CAP` to `HARD_CAP` of `MINT` that has collected `current_amount`.
+pub fn fundraiser_account(mollusk: &Mollusk, current_amount: u64) -> (Pubkey, Account) {
+    let (fundraiser_pda, bump) = fundraiser_pda();
+
+    let fundraiser = Fundraiser {
+        maker: *MAKER.as_array(),
+        mint_to_raise: *MINT.as_array(),
+        soft_cap: SOFT_CAP,
+        hard_cap: HARD_CAP,
+        current_amount,
+        min_contribution: MIN_CONTRIBUTION * ONE_TOKEN,
+        max_contribution: SOFT_CAP * MAX_CONTRIBUTION_PERCENTAGE as u64 / 100,
+        time_started: TIME_STARTED,
+        duration: DURATION,
+        bump,
+        is_initialized: true,
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&fundraiser).to_vec() };
+
+    (fundraiser_pda, account)
+}
+
+/// Contributor account of `CONTRIBUTOR` holding a running total of `amount`.
+pub fn contributor_account(mollusk: &Mollusk, amount: u64) -> (Pubkey, Account) {
+    let (contributor_pda, bump) = contributor_pda(&CONTRIBUTOR);
+
+    let contributor = Contributor {
+        amount,
+        bump,
+        is_initialized: true,
+    };
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &PROGRAM,
+    );
+    account.data = unsafe { to_bytes(&contributor).to_vec() };
+
+    (contributor_pda, account)
+}
+
+/// Initialize a fundraiser for `SOFT_CAP` to `HARD_CAP` of `MINT` over `DURATION` days.
+pub fn initialize(mollusk: &Mollusk) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (fundraiser_pda, bump) = fundraiser_pda();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(MINT, false),
+        AccountMeta::new(fundraiser_pda, false),
+        AccountMeta::new_readonly(VAULT, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Create the instruction data
+    let ix_data = InitializeIxData {
+        maker: *MAKER.as_array(),
+        soft_cap: SOFT_CAP,
+        hard_cap: HARD_CAP,
+        min_contribution: MIN_CONTRIBUTION,
+        duration: DURATION,
+        max_contribution_percentage: MAX_CONTRIBUTION_PERCENTAGE,
+        bump,
+    };
+
+    // Ix discriminator = 0
+    let mut ser_ix_data = vec![0];
+
+    // Serialize the instruction data
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    // Create instruction
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (MAKER, system_account(10 * LAMPORTS_PER_SOL)),
+        (MINT, mint_account(mollusk, &MAKER, DECIMALS)),
+        (fundraiser_pda, system_account(0)),
+        (VAULT, token_account(mollusk, &MINT, &fundraiser_pda, 0)),
+        (RENT, rent_account(mollusk)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// `CONTRIBUTOR` puts `amount` into a fundraiser that has collected `current_amount`,
+/// with `contributed` already on record (0 opens the Contributor account).
+pub fn contribute(
+    mollusk: &Mollusk,
+    amount: u64,
+    current_amount: u64,
+    contributed: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (fundraiser_pda, fundraiser_account) = fundraiser_account(mollusk, current_amount);
+    let (contributor_pda, contributor_bump) = contributor_pda(&CONTRIBUTOR);
+
+    let contributor_account = if contributed == 0 {
+        system_account(0)
+    } else {
+        contributor_account(mollusk, contributed).1
+    };
+
+    let contributor_ata = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(CONTRIBUTOR, true),
+        AccountMeta::new_readonly(MINT, false),
+        AccountMeta::new(fundraiser_pda, false),
+        AccountMeta::new(contributor_pda, false),
+        AccountMeta::new(contributor_ata, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(system_program, false),
+    ];
+
+    // Create the instruction data
+    let ix_data = ContributeIxData {
+        amount,
+        contributor_bump,
+    };
+
+    // Ix discriminator = 1
+    let mut ser_ix_data = vec![1];
+
+    // Serialize the instruction data
+    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+
+    // Create instruction
+    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (CONTRIBUTOR, system_account(LAMPORTS_PER_SOL)),
+        (MINT, mint_account(mollusk, &MAKER, DECIMALS)),
+        (fundraiser_pda, fundraiser_account),
+        (contributor_pda, contributor_account),
+        (
+            contributor_ata,
+            token_account(mollusk, &MINT, &CONTRIBUTOR, SOFT_CAP),
+        ),
+        (
+            VAULT,
+            token_account(mollusk, &MINT, &fundraiser_pda, current_amount),
+        ),
+        (RENT, rent_account(mollusk)),
+        (token_program, token_program_account),
+        (system_program, system_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// Maker claims a vault holding `vault_amount`.
+pub fn check_contributions(
+    mollusk: &Mollusk,
+    vault_amount: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (system_program, system_program_account) = program::keyed_account_for_system_program();
+    let (token_program, token_program_account) = token_program_account();
+
+    let (fundraiser_pda, fundraiser_account) = fundraiser_account(mollusk, vault_amount);
+
+    let maker_ata = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(MINT, false),
+        AccountMeta::new(fundraiser_pda, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new(maker_ata, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Ix discriminator = 2, check contributions has no instruction data
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[2], ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (MINT, mint_account(mollusk, &MAKER, DECIMALS)),
+        (fundraiser_pda, fundraiser_account),
+        (
+            VAULT,
+            token_account(mollusk, &MINT, &fundraiser_pda, vault_amount),
+        ),
+        (maker_ata, token_account(mollusk, &MINT, &MAKER, 0)),
+        (system_program, system_program_account),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// `CONTRIBUTOR` takes back `contributed` from a vault holding `vault_amount`.
+pub fn refund(
+    mollusk: &Mollusk,
+    vault_amount: u64,
+    contributed: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (token_program, token_program_account) = token_program_account();
+
+    let (fundraiser_pda, fundraiser_account) = fundraiser_account(mollusk, vault_amount);
+    let (contributor_pda, contributor_account) = contributor_account(mollusk, contributed);
+
+    let contributor_ata = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(CONTRIBUTOR, true),
+        AccountMeta::new_readonly(MINT, false),
+        AccountMeta::new(fundraiser_pda, false),
+        AccountMeta::new(contributor_pda, false),
+        AccountMeta::new(contributor_ata, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Ix discriminator = 3, refund has no instruction data
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[3], ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (CONTRIBUTOR, system_account(LAMPORTS_PER_SOL)),
+        (MINT, mint_account(mollusk, &MAKER, DECIMALS)),
+        (fundraiser_pda, fundraiser_account),
+        (contributor_pda, contributor_account),
+        (
+            contributor_ata,
+            token_account(mollusk, &MINT, &CONTRIBUTOR, 0),
+        ),
+        (
+            VAULT,
+            token_account(mollusk, &MINT, &fundraiser_pda, vault_amount),
+        ),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
+
+/// Maker closes a fundraiser that has `current_amount` on record and a vault holding `vault_amount`.
+pub fn close_fundraiser(
+    mollusk: &Mollusk,
+    current_amount: u64,
+    vault_amount: u64,
+) -> (Instruction, Vec<(Pubkey, Account)>) {
+    let (token_program, token_program_account) = token_program_account();
+
+    let (fundraiser_pda, fundraiser_account) = fundraiser_account(mollusk, current_amount);
+
+    let maker_ata = Pubkey::new_unique();
+
+    //Push the accounts in to the instruction_accounts vec!
+    let ix_accounts = vec![
+        AccountMeta::new(MAKER, true),
+        AccountMeta::new_readonly(MINT, false),
+        AccountMeta::new(fundraiser_pda, false),
+        AccountMeta::new(VAULT, false),
+        AccountMeta::new(maker_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Ix discriminator = 4, close fundraiser has no instruction data
+    let instruction = Instruction::new_with_bytes(PROGRAM, &[4], ix_accounts);
+
+    // Create tx_accounts vec
+    let tx_accounts = vec![
+        (MAKER, system_account(LAMPORTS_PER_SOL)),
+        (MINT, mint_account(mollusk, &MAKER, DECIMALS)),
+        (fundraiser_pda, fundraiser_account),
+        (
+            VAULT,
+            token_account(mollusk, &MINT, &fundraiser_pda, vault_amount),
+        ),
+        (maker_ata, token_account(mollusk, &MINT, &MAKER, 0)),
+        (token_program, token_program_account),
+    ];
+
+    (instruction, tx_accounts)
+}
@@ -1,146 +1,554 @@
-use mollusk_svm::result::{Check, ProgramResult};
-use mollusk_svm::{program, Mollusk};
-use solana_sdk::account::Account;
-use solana_sdk::instruction::{AccountMeta, Instruction};
+mod common;
+
+use common::*;
+use mollusk_svm::result::Check;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use solana_sdk::pubkey;
+use solana_sdk::program_error::ProgramError;
 use solana_sdk::pubkey::Pubkey;
-extern crate alloc;
-use alloc::vec;
 
-use solana_pinocchio_starter::instruction::{InitializeMyStateIxData, UpdateMyStateIxData};
-use solana_pinocchio_starter::state::{to_bytes, DataLen, MyState, State};
-use solana_pinocchio_starter::ID;
-use solana_sdk::rent::Rent;
-use solana_sdk::sysvar::Sysvar;
+use solana_pinocchio_starter::error::MyProgramError;
+use solana_pinocchio_starter::state::{load_acc, Contributor, DataLen, Fundraiser};
+
+#[test]
+fn test_initialize() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = initialize(&mollusk);
+
+    let (fundraiser_pda, bump) = fundraiser_pda();
+
+    let init_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&fundraiser_pda)
+                .owner(&PROGRAM)
+                .space(Fundraiser::LEN)
+                .build(),
+        ],
+    );
+
+    let fundraiser =
+        unsafe { *load_acc::<Fundraiser>(&init_res.resulting_accounts[2].1.data).unwrap() };
+    assert_eq!(fundraiser.maker, *MAKER.as_array());
+    assert_eq!(fundraiser.mint_to_raise, *MINT.as_array());
+    assert_eq!(fundraiser.soft_cap, SOFT_CAP);
+    assert_eq!(fundraiser.hard_cap, HARD_CAP);
+    assert_eq!(fundraiser.current_amount, 0);
+    assert_eq!(fundraiser.min_contribution, 2 * ONE_TOKEN);
+    assert_eq!(fundraiser.max_contribution, 10 * ONE_TOKEN);
+    assert_eq!(fundraiser.time_started, TIME_STARTED);
+    assert_eq!(fundraiser.duration, DURATION);
+    assert_eq!(fundraiser.bump, bump);
+}
+
+#[test]
+fn test_initialize_goal_too_low() {
+    let mollusk = mollusk();
+
+    let (mut instruction, tx_accounts) = initialize(&mollusk);
+
+    // soft_cap sits right after the maker key, set it below 3 whole tokens
+    instruction.data[33..41].copy_from_slice(&(2 * ONE_TOKEN).to_le_bytes());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::SoftCapTooLow as u32,
+        ))],
+    );
+}
 
-pub const PROGRAM: Pubkey = Pubkey::new_from_array(ID);
+#[test]
+fn test_initialize_hard_cap_below_soft_cap() {
+    let mollusk = mollusk();
 
-pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+    let (mut instruction, tx_accounts) = initialize(&mollusk);
 
-pub const PAYER: Pubkey = pubkey!("41LzznNicELmc5iCR9Jxke62a3v1VhzpBYodQF5AQwHX");
+    // hard_cap follows soft_cap
+    instruction.data[41..49].copy_from_slice(&(SOFT_CAP - 1).to_le_bytes());
 
-pub fn mollusk() -> Mollusk {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/solana_pinocchio_starter");
-    mollusk
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidCaps as u32,
+        ))],
+    );
 }
 
-pub fn get_rent_data() -> Vec<u8> {
-    let rent = Rent::default();
-    unsafe {
-        core::slice::from_raw_parts(&rent as *const Rent as *const u8, Rent::size_of()).to_vec()
+#[test]
+fn test_initialize_cap_out_of_range() {
+    let mollusk = mollusk();
+
+    for percentage in [0, 101] {
+        let (mut instruction, tx_accounts) = initialize(&mollusk);
+
+        // max_contribution_percentage follows min_contribution and duration
+        instruction.data[58] = percentage;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &tx_accounts,
+            &[Check::err(ProgramError::Custom(
+                MyProgramError::InvalidContributionLimits as u32,
+            ))],
+        );
     }
 }
 
 #[test]
-fn test_initialize_mystate() {
+fn test_initialize_zero_duration() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, system_account) = program::keyed_account_for_system_program();
+    let (mut instruction, tx_accounts) = initialize(&mollusk);
 
-    // Create the PDA
-    let (mystate_pda, bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    // duration follows min_contribution
+    instruction.data[57] = 0;
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
-    let mystate_account = Account::new(0, 0, &system_program);
-    let min_balance = mollusk.sysvars.rent.minimum_balance(Rent::size_of());
-    let mut rent_account = Account::new(min_balance, Rent::size_of(), &RENT);
-    rent_account.data = get_rent_data();
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidInstructionData as u32,
+        ))],
+    );
+}
 
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-        AccountMeta::new_readonly(RENT, false),
-        AccountMeta::new_readonly(system_program, false),
-    ];
+#[test]
+fn test_initialize_min_above_cap() {
+    let mollusk = mollusk();
 
-    // Create the instruction data
-    let ix_data = InitializeMyStateIxData {
-        owner: *PAYER.as_array(),
-        data: [1; 32],
-        bump,
-    };
+    let (mut instruction, tx_accounts) = initialize(&mollusk);
 
-    // Ix discriminator = 0
-    let mut ser_ix_data = vec![0];
+    // 11 whole tokens minimum against a 10 token cap
+    instruction.data[49..57].copy_from_slice(&11u64.to_le_bytes());
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::InvalidContributionLimits as u32,
+        ))],
+    );
+}
 
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
+#[test]
+fn test_contribute() {
+    let mollusk = mollusk();
 
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-        (RENT, rent_account.clone()),
-        (system_program, system_account.clone()),
-    ];
+    let (instruction, tx_accounts) = contribute(&mollusk, 5 * ONE_TOKEN, 0, 0);
+
+    let (contributor_pda, _) = contributor_pda(&CONTRIBUTOR);
+
+    let contribute_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&contributor_pda)
+                .owner(&PROGRAM)
+                .space(Contributor::LEN)
+                .build(),
+        ],
+    );
+
+    assert_eq!(
+        token_amount(&contribute_res.resulting_accounts[4].1),
+        SOFT_CAP - 5 * ONE_TOKEN
+    );
+    assert_eq!(
+        token_amount(&contribute_res.resulting_accounts[5].1),
+        5 * ONE_TOKEN
+    );
+
+    let fundraiser =
+        unsafe { *load_acc::<Fundraiser>(&contribute_res.resulting_accounts[2].1.data).unwrap() };
+    assert_eq!(fundraiser.current_amount, 5 * ONE_TOKEN);
+
+    let contributor =
+        unsafe { *load_acc::<Contributor>(&contribute_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(contributor.amount, 5 * ONE_TOKEN);
+}
+
+#[test]
+fn test_contribute_again() {
+    let mollusk = mollusk();
 
-    let init_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+    // The wallet already put in 4 tokens, the running total grows to 9
+    let (instruction, tx_accounts) =
+        contribute(&mollusk, 5 * ONE_TOKEN, 4 * ONE_TOKEN, 4 * ONE_TOKEN);
 
-    assert!(init_res.program_result == ProgramResult::Success);
+    let contribute_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    let contributor =
+        unsafe { *load_acc::<Contributor>(&contribute_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(contributor.amount, 9 * ONE_TOKEN);
 }
 
 #[test]
-fn test_update_mystate() {
+fn test_contribute_top_up_below_minimum() {
     let mollusk = mollusk();
 
-    //system program and system account
-    let (system_program, _system_account) = program::keyed_account_for_system_program();
+    // Half a token is fine once the wallet's running total is over the minimum
+    let (instruction, tx_accounts) =
+        contribute(&mollusk, ONE_TOKEN / 2, 4 * ONE_TOKEN, 4 * ONE_TOKEN);
 
-    // Create the PDA
-    let (mystate_pda, _bump) =
-        Pubkey::find_program_address(&[MyState::SEED.as_bytes(), &PAYER.to_bytes()], &PROGRAM);
+    let contribute_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
 
-    //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    let contributor =
+        unsafe { *load_acc::<Contributor>(&contribute_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(contributor.amount, 4 * ONE_TOKEN + ONE_TOKEN / 2);
+}
 
-    let rent = mollusk.sysvars.rent.minimum_balance(MyState::LEN);
+#[test]
+fn test_contribute_too_small() {
+    let mollusk = mollusk();
 
-    let mut mystate_account = Account::new(rent, MyState::LEN, &ID.into());
+    // First contribution has to reach the 2 token minimum on its own
+    let (instruction, tx_accounts) = contribute(&mollusk, ONE_TOKEN, 0, 0);
 
-    let my_state = MyState {
-        is_initialized: true,
-        owner: *PAYER.as_array(),
-        state: State::Initialized,
-        data: [1; 32],
-        update_count: 0,
-    };
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::ContributionTooSmall as u32,
+        ))],
+    );
+}
 
-    mystate_account.data = unsafe { to_bytes(&my_state).to_vec() };
+#[test]
+fn test_contribute_too_big() {
+    let mollusk = mollusk();
 
-    //Push the accounts in to the instruction_accounts vec!
-    let ix_accounts = vec![
-        AccountMeta::new(PAYER, true),
-        AccountMeta::new(mystate_pda, false),
-    ];
+    // 10% of the goal is 10 tokens, 6 on record plus 5 more goes over
+    let (instruction, tx_accounts) =
+        contribute(&mollusk, 5 * ONE_TOKEN, 6 * ONE_TOKEN, 6 * ONE_TOKEN);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::ContributionTooBig as u32,
+        ))],
+    );
+}
 
-    // Create the instruction data
-    let ix_data = UpdateMyStateIxData { data: [1; 32] };
+#[test]
+fn test_contribute_after_end() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = contribute(&mollusk, 5 * ONE_TOKEN, 0, 0);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::FundraiserEnded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_contribute_over_hard_cap() {
+    let mollusk = mollusk();
+
+    // Only 3 tokens of room left, the other 2 stay in the contributor's ATA
+    let (instruction, tx_accounts) =
+        contribute(&mollusk, 5 * ONE_TOKEN, HARD_CAP - 3 * ONE_TOKEN, 0);
+
+    let contribute_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
+
+    assert_eq!(
+        token_amount(&contribute_res.resulting_accounts[4].1),
+        SOFT_CAP - 3 * ONE_TOKEN
+    );
+    assert_eq!(
+        token_amount(&contribute_res.resulting_accounts[5].1),
+        HARD_CAP
+    );
+
+    let fundraiser =
+        unsafe { *load_acc::<Fundraiser>(&contribute_res.resulting_accounts[2].1.data).unwrap() };
+    assert_eq!(fundraiser.current_amount, HARD_CAP);
+
+    let contributor =
+        unsafe { *load_acc::<Contributor>(&contribute_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(contributor.amount, 3 * ONE_TOKEN);
+}
 
-    // Ix discriminator = 1
-    let mut ser_ix_data = vec![1];
+#[test]
+fn test_contribute_fills_hard_cap_below_minimum() {
+    let mollusk = mollusk();
+
+    // One token of room is under the 2 token minimum but still gets raised
+    let (instruction, tx_accounts) = contribute(&mollusk, 5 * ONE_TOKEN, HARD_CAP - ONE_TOKEN, 0);
 
-    // Serialize the instruction data
-    ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+    let contribute_res =
+        mollusk.process_and_validate_instruction(&instruction, &tx_accounts, &[Check::success()]);
 
-    // Create instruction
-    let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
-    // Create tx_accounts vec
-    let tx_accounts = &vec![
-        (PAYER, payer_account.clone()),
-        (mystate_pda, mystate_account.clone()),
-    ];
+    let contributor =
+        unsafe { *load_acc::<Contributor>(&contribute_res.resulting_accounts[3].1.data).unwrap() };
+    assert_eq!(contributor.amount, ONE_TOKEN);
+}
 
-    let update_res =
-        mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
+#[test]
+fn test_contribute_hard_cap_reached() {
+    let mollusk = mollusk();
 
-    assert!(update_res.program_result == ProgramResult::Success);
+    let (instruction, tx_accounts) = contribute(&mollusk, 5 * ONE_TOKEN, HARD_CAP, 0);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::HardCapReached as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_check_contributions() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = check_contributions(&mollusk, SOFT_CAP);
+
+    let fundraiser_rent = tx_accounts[2].1.lamports;
+    let vault_rent = tx_accounts[3].1.lamports;
+
+    let check_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[2].0).lamports(0).build(),
+            Check::account(&VAULT).lamports(0).build(),
+            Check::account(&MAKER)
+                .lamports(LAMPORTS_PER_SOL + fundraiser_rent + vault_rent)
+                .build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&check_res.resulting_accounts[4].1), SOFT_CAP);
+}
+
+#[test]
+fn test_check_contributions_hard_cap_early() {
+    let mollusk = mollusk();
+
+    // Still before the deadline, but the hard cap leaves no room to contribute
+    let (instruction, tx_accounts) = check_contributions(&mollusk, HARD_CAP);
+
+    let check_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[2].0).lamports(0).build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&check_res.resulting_accounts[4].1), HARD_CAP);
+}
+
+#[test]
+fn test_check_contributions_before_end() {
+    let mollusk = mollusk();
+
+    // Soft cap is met, but the fundraiser can still take contributions
+    let (instruction, tx_accounts) = check_contributions(&mollusk, SOFT_CAP);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::FundraiserNotEnded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_check_contributions_target_not_met() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = check_contributions(&mollusk, SOFT_CAP - 1);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::TargetNotMet as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_check_contributions_vault_topped_up() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    // The vault holds the soft cap, but part of it never went through Contribute
+    let (instruction, mut tx_accounts) = check_contributions(&mollusk, SOFT_CAP);
+    tx_accounts[2].1 = fundraiser_account(&mollusk, SOFT_CAP - ONE_TOKEN).1;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::TargetNotMet as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_refund() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = refund(&mollusk, 50 * ONE_TOKEN, 5 * ONE_TOKEN);
+
+    let contributor_rent = tx_accounts[3].1.lamports;
+
+    let refund_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[3].0).lamports(0).build(),
+            Check::account(&CONTRIBUTOR)
+                .lamports(LAMPORTS_PER_SOL + contributor_rent)
+                .build(),
+        ],
+    );
+
+    assert_eq!(
+        token_amount(&refund_res.resulting_accounts[4].1),
+        5 * ONE_TOKEN
+    );
+    assert_eq!(
+        token_amount(&refund_res.resulting_accounts[5].1),
+        45 * ONE_TOKEN
+    );
+
+    let fundraiser =
+        unsafe { *load_acc::<Fundraiser>(&refund_res.resulting_accounts[2].1.data).unwrap() };
+    assert_eq!(fundraiser.current_amount, 45 * ONE_TOKEN);
+}
+
+#[test]
+fn test_refund_before_end() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = refund(&mollusk, 50 * ONE_TOKEN, 5 * ONE_TOKEN);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::FundraiserNotEnded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_refund_target_met() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = refund(&mollusk, SOFT_CAP, 5 * ONE_TOKEN);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::TargetMet as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_refund_other_wallet() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (mut instruction, mut tx_accounts) = refund(&mollusk, 50 * ONE_TOKEN, 5 * ONE_TOKEN);
+
+    // Someone else signs for CONTRIBUTOR's Contributor account
+    let thief = Pubkey::new_unique();
+    instruction.accounts[0].pubkey = thief;
+    tx_accounts[0].0 = thief;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::PdaMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_close_fundraiser() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    // Every contribution was refunded, one token was sent straight to the vault
+    let (instruction, tx_accounts) = close_fundraiser(&mollusk, 0, ONE_TOKEN);
+
+    let fundraiser_rent = tx_accounts[2].1.lamports;
+    let vault_rent = tx_accounts[3].1.lamports;
+
+    let close_res = mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[
+            Check::success(),
+            Check::account(&tx_accounts[2].0).lamports(0).build(),
+            Check::account(&VAULT).lamports(0).build(),
+            Check::account(&MAKER)
+                .lamports(LAMPORTS_PER_SOL + fundraiser_rent + vault_rent)
+                .build(),
+        ],
+    );
+
+    assert_eq!(token_amount(&close_res.resulting_accounts[4].1), ONE_TOKEN);
+}
+
+#[test]
+fn test_close_fundraiser_before_end() {
+    let mollusk = mollusk();
+
+    let (instruction, tx_accounts) = close_fundraiser(&mollusk, 0, 0);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::FundraiserNotEnded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_close_fundraiser_refunds_pending() {
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = TIME_ENDED;
+
+    let (instruction, tx_accounts) = close_fundraiser(&mollusk, 5 * ONE_TOKEN, 5 * ONE_TOKEN);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &tx_accounts,
+        &[Check::err(ProgramError::Custom(
+            MyProgramError::RefundsPending as u32,
+        ))],
+    );
 }
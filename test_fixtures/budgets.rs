@@ -0,0 +1,31 @@
+//! Compute unit budgets for the programs' `cargo bench` runs, shared through
+//! `#[path]`. Each program checks in a reviewed ceiling per benched instruction,
+//! raising one is part of the change that needs the extra units.
+
+/// Checked-in budgets, one `Name units` entry per line, `#` starts a comment.
+pub const BUDGETS: &str = "benches/compute_unit_budgets.txt";
+
+/// Checked-in budget of the benched instruction `name`.
+pub fn budget(name: &str) -> u64 {
+    let budgets = std::fs::read_to_string(BUDGETS)
+        .unwrap_or_else(|err| panic!("can't read {BUDGETS}: {err}"));
+
+    budgets
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(' '))
+        .find(|(bench, _)| *bench == name)
+        .and_then(|(_, units)| units.trim().parse().ok())
+        .unwrap_or_else(|| panic!("{name} has no compute unit budget in {BUDGETS}"))
+}
+
+/// Fails when an instruction has no budget or uses more than it.
+pub fn check_budgets(consumed: &[(&str, u64)]) {
+    for (name, consumed) in consumed {
+        let budget = budget(name);
+        assert!(
+            *consumed <= budget,
+            "{name} used {consumed} CUs, over its budget of {budget}"
+        );
+    }
+}
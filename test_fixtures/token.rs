@@ -0,0 +1,84 @@
+//! Mollusk account fixtures for the SPL Token programs, shared by the programs'
+//! test suites through `#[path]` so each `tests/common` only keeps what is
+//! specific to its own program.
+
+use mollusk_svm::{program, Mollusk};
+use solana_sdk::account::Account;
+use solana_sdk::program_option::COption;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::sysvar::Sysvar;
+
+pub const TOKEN_PROGRAM: Pubkey = spl_token::ID;
+
+pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+
+pub fn token_program_account() -> (Pubkey, Account) {
+    (TOKEN_PROGRAM, program::create_program_account_loader_v3(&TOKEN_PROGRAM))
+}
+
+pub fn rent_account(mollusk: &Mollusk) -> Account {
+    let rent = Rent::default();
+    let mut rent_account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(Rent::size_of()),
+        Rent::size_of(),
+        &RENT,
+    );
+    rent_account.data = unsafe {
+        core::slice::from_raw_parts(&rent as *const Rent as *const u8, Rent::size_of()).to_vec()
+    };
+    rent_account
+}
+
+pub fn system_account(lamports: u64) -> Account {
+    Account::new(lamports, 0, &solana_sdk::system_program::ID)
+}
+
+/// Initialized SPL Token mint with `authority` as mint authority.
+pub fn mint_account(mollusk: &Mollusk, authority: &Pubkey, decimals: u8) -> Account {
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(spl_token::state::Mint::LEN),
+        spl_token::state::Mint::LEN,
+        &TOKEN_PROGRAM,
+    );
+    spl_token::state::Mint {
+        mint_authority: COption::Some(*authority),
+        supply: u64::MAX / 2,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut account.data);
+    account
+}
+
+/// Initialized SPL Token account holding `amount` of `mint` for `owner`.
+pub fn token_account(mollusk: &Mollusk, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let mut account = Account::new(
+        mollusk.sysvars.rent.minimum_balance(spl_token::state::Account::LEN),
+        spl_token::state::Account::LEN,
+        &TOKEN_PROGRAM,
+    );
+    spl_token::state::Account {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut account.data);
+    account
+}
+
+/// Balance of a token account of either token program, ignoring any Token-2022
+/// extensions after the base account.
+pub fn token_amount(account: &Account) -> u64 {
+    spl_token::state::Account::unpack(&account.data[..spl_token::state::Account::LEN])
+        .unwrap()
+        .amount
+}